@@ -10,18 +10,40 @@ pub enum Error<'src> {
   UnaryOperator(Token<'src>),
   #[error("invalid binary operator token")]
   BinaryOperator(Token<'src>),
+  #[error("invalid equality operator token")]
+  EqualityOperator(Token<'src>),
   #[error("invalid comparison operator token")]
   ComparisonOperator(Token<'src>),
   #[error("invalid factor operator token")]
   FactorOperator(Token<'src>),
+  #[error("invalid power operator token")]
+  PowerOperator(Token<'src>),
+  #[error("invalid bitwise and operator token")]
+  BitAndOperator(Token<'src>),
+  #[error("invalid bitwise or operator token")]
+  BitOrOperator(Token<'src>),
+  #[error("invalid bitwise xor operator token")]
+  BitXorOperator(Token<'src>),
+  #[error("invalid shift operator token")]
+  ShiftOperator(Token<'src>),
   #[error("invalid term operator token")]
   TermOperator(Token<'src>),
   #[error("invalid comma operator token")]
   CommaOperator(Token<'src>),
+  #[error("invalid nullish operator token")]
+  NullishOperator(Token<'src>),
+  #[error("invalid range operator token")]
+  RangeOperator(Token<'src>),
   #[error("invalid ternary then branch token")]
   TernaryThenOperator(Token<'src>),
   #[error("invalid ternary else branch token")]
   TernaryElseOperator(Token<'src>),
   #[error("invalid literal token")]
   Literal(Token<'src>),
+  #[error("invalid identifier token")]
+  Identifier(Token<'src>),
+  #[error("invalid assignment operator token")]
+  AssignmentOperator(Token<'src>),
+  #[error("invalid update operator token")]
+  UpdateOperator(Token<'src>),
 }
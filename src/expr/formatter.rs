@@ -1,4 +1,4 @@
-use super::{Expr, ExprVisitor};
+use super::{Expr, ExprVisitor, Identifier};
 
 /// Abstract Syntax Tree (AST) formatter in List style format.
 pub struct AstFormatter;
@@ -19,6 +19,56 @@ impl ExprVisitor<String> for AstFormatter {
         format!("({} {})", operator, self.visit(right))
       }
       Expr::Literal(literal_token) => format!("{literal_token}"),
+      Expr::Variable(name) => format!("{name}"),
+      Expr::Assign { name, operator, value } => {
+        format!("({} {} {})", operator, name, self.visit(value))
+      }
+      Expr::Update { operator, target, prefix: true } => {
+        format!("(pre{} {})", operator, self.visit(target))
+      }
+      Expr::Update { operator, target, prefix: false } => {
+        format!("(post{} {})", operator, self.visit(target))
+      }
+      Expr::Lambda { parameters, body } => {
+        let parameters: Vec<_> = parameters.iter().map(Identifier::name).collect();
+        format!("(lambda ({}) {})", parameters.join(" "), self.visit(body))
+      }
+      Expr::Call { callee, arguments } => {
+        let arguments: String = arguments.iter().map(|a| format!(" {}", self.visit(a))).collect();
+        format!("(call {}{arguments})", self.visit(callee))
+      }
+      Expr::Get { object, name, optional } => {
+        format!("({} {} {name})", if *optional { "?." } else { "." }, self.visit(object))
+      }
+      Expr::OptionalChain(chain) => format!("(chain {})", self.visit(chain)),
+      Expr::List(elements) => {
+        let elements: String = elements.iter().map(|e| format!(" {}", self.visit(e))).collect();
+        format!("(list{elements})")
+      }
+      Expr::Map(entries) => {
+        let entries: String = entries
+          .iter()
+          .map(|(key, value)| format!(" ({} {})", self.visit(key), self.visit(value)))
+          .collect();
+        format!("(map{entries})")
+      }
+      Expr::Index { object, index } => {
+        format!("(index {} {})", self.visit(object), self.visit(index))
+      }
+      Expr::Slice { object, start, end } => {
+        let bound = |b: &Option<Box<Expr>>| b.as_ref().map_or("_".to_string(), |b| self.visit(b));
+        format!("(slice {} {} {})", self.visit(object), bound(start), bound(end))
+      }
+      Expr::IndexSet { object, index, operator, value } => format!(
+        "({} (index {} {}) {})",
+        operator,
+        self.visit(object),
+        self.visit(index),
+        self.visit(value)
+      ),
+      Expr::Set { object, name, operator, value } => {
+        format!("({} (. {} {name}) {})", operator, self.visit(object), self.visit(value))
+      }
     }
   }
 }
@@ -42,13 +92,67 @@ impl ExprVisitor<String> for RpnFormatter {
       Expr::Grouping(expr) => self.visit(expr),
       Expr::Unary { operator, right } => format!("{}{}", operator, self.visit(right)),
       Expr::Literal(literal_token) => format!("{literal_token}"),
+      Expr::Variable(name) => format!("{name}"),
+      Expr::Assign { name, operator, value } => {
+        format!("{} {} {}", name, self.visit(value), operator)
+      }
+      Expr::Update { operator, target, prefix: true } => {
+        format!("{}{}", operator, self.visit(target))
+      }
+      Expr::Update { operator, target, prefix: false } => {
+        format!("{}{}", self.visit(target), operator)
+      }
+      Expr::Lambda { parameters, body } => {
+        let parameters: Vec<_> = parameters.iter().map(Identifier::name).collect();
+        format!("({}) {} =>", parameters.join(" "), self.visit(body))
+      }
+      Expr::Call { callee, arguments } => {
+        let count = arguments.len();
+        let arguments: String = arguments.iter().map(|a| format!("{} ", self.visit(a))).collect();
+        format!("{} {arguments}call({count})", self.visit(callee))
+      }
+      Expr::Get { object, name, optional } => {
+        format!("{} {}{name}", self.visit(object), if *optional { "?." } else { "." })
+      }
+      Expr::OptionalChain(chain) => format!("{} chain", self.visit(chain)),
+      Expr::List(elements) => {
+        let count = elements.len();
+        let elements: String = elements.iter().map(|e| format!("{} ", self.visit(e))).collect();
+        format!("{elements}[{count}]")
+      }
+      Expr::Map(entries) => {
+        let count = entries.len();
+        let entries: String = entries
+          .iter()
+          .map(|(key, value)| format!("{} {} ", self.visit(key), self.visit(value)))
+          .collect();
+        format!("{entries}{{{count}}}")
+      }
+      Expr::Index { object, index } => format!("{} {} []", self.visit(object), self.visit(index)),
+      Expr::Slice { object, start, end } => {
+        let bound = |b: &Option<Box<Expr>>| b.as_ref().map_or("_".to_string(), |b| self.visit(b));
+        format!("{} {} {} [:]", self.visit(object), bound(start), bound(end))
+      }
+      Expr::IndexSet { object, index, operator, value } => {
+        format!("{} {} {} []{}", self.visit(object), self.visit(index), self.visit(value), operator)
+      }
+      Expr::Set { object, name, operator, value } => {
+        format!("{} {} .{name}{}", self.visit(object), self.visit(value), operator)
+      }
     }
   }
 }
 
 #[cfg(test)]
 mod tests {
-  use crate::token::{Token, TokenKind};
+  use claims::{assert_ok, assert_some};
+  use rstest::rstest;
+
+  use crate::{
+    parser::Parser,
+    scanner::Scanner,
+    token::{Token, TokenKind},
+  };
 
   use super::*;
 
@@ -149,4 +253,31 @@ mod tests {
       assert_eq!(expected, ast, "{error}");
     }
   }
+
+  /// formats the expression parsed from `src` in RPN.
+  fn rpn(src: &str) -> String {
+    let tokens = Scanner::new(src).map(|t| t.unwrap());
+    let expr = assert_ok!(assert_some!(Parser::new(tokens).next()));
+    RpnFormatter.visit(&expr)
+  }
+
+  #[rstest(src, expected)]
+  #[case::list("[1, a]", "1 a [2]")]
+  #[case::empty_list("[]", "[0]")]
+  #[case::map("{a: 1, b: 2}", "a 1 b 2 {2}")]
+  #[case::index("xs[0]", "xs 0 []")]
+  #[case::slice("xs[1:3]", "xs 1 3 [:]")]
+  #[case::slice_without_bounds("xs[:]", "xs _ _ [:]")]
+  #[case::index_set("xs[0] += 1", "xs 0 1 []+=")]
+  #[case::call("f(a, 1)", "f a 1 call(2)")]
+  #[case::call_without_arguments("f()", "f call(0)")]
+  #[case::get("a.b", "a .b")]
+  #[case::optional_chain("a?.b.c", "a ?.b .c chain")]
+  #[case::lambda("(a, b) => a + b", "(a b) a b + =>")]
+  #[case::prefix_update("++x", "++x")]
+  #[case::postfix_update("x--", "x--")]
+  #[case::set("a.b = 1", "a 1 .b=")]
+  fn print_rpn_ok(src: &str, expected: &str) {
+    assert_eq!(expected, rpn(src));
+  }
 }
@@ -6,8 +6,10 @@ pub use self::{
   error::{Error, Result},
   formatter::{AstFormatter, RpnFormatter},
   terminal::{
-    BinaryOperator, CommaOperator, ComparisonOperator, FactorOperator, Literal, TermOperator,
-    Terminal, TernaryElseOperator, TernaryThenOperator, UnaryOperator,
+    AssignmentOperator, BinaryOperator, BitAndOperator, BitOrOperator, BitXorOperator,
+    CommaOperator, ComparisonOperator, EqualityOperator, FactorOperator, Identifier, Literal,
+    NullishOperator, PowerOperator, RangeOperator, ShiftOperator, TermOperator, Terminal,
+    TernaryElseOperator, TernaryThenOperator, UnaryOperator, UpdateOperator,
   },
 };
 
@@ -19,6 +21,19 @@ pub enum Expr {
   Grouping(Box<Expr>),
   Unary { operator: UnaryOperator, right: Box<Expr> },
   Literal(Literal),
+  Variable(Identifier),
+  Assign { name: Identifier, operator: AssignmentOperator, value: Box<Expr> },
+  Update { operator: UpdateOperator, target: Box<Expr>, prefix: bool },
+  Lambda { parameters: Vec<Identifier>, body: Box<Expr> },
+  Call { callee: Box<Expr>, arguments: Vec<Expr> },
+  Get { object: Box<Expr>, name: Identifier, optional: bool },
+  OptionalChain(Box<Expr>),
+  List(Vec<Expr>),
+  Map(Vec<(Expr, Expr)>),
+  Index { object: Box<Expr>, index: Box<Expr> },
+  Slice { object: Box<Expr>, start: Option<Box<Expr>>, end: Option<Box<Expr>> },
+  IndexSet { object: Box<Expr>, index: Box<Expr>, operator: AssignmentOperator, value: Box<Expr> },
+  Set { object: Box<Expr>, name: Identifier, operator: AssignmentOperator, value: Box<Expr> },
 }
 
 impl Expr {
@@ -62,6 +77,166 @@ impl Expr {
   pub fn grouping(expression: Expr) -> Self {
     Expr::Grouping(Box::new(expression))
   }
+  /// creates a new variable expression.
+  ///
+  /// # Returns
+  /// A new variable expression.
+  pub fn variable(name: Identifier) -> Self {
+    Expr::Variable(name)
+  }
+  /// creates a new variable assignment expression.
+  ///
+  /// # Returns
+  /// A new variable assignment expression.
+  pub fn assign(name: Identifier, operator: AssignmentOperator, value: Expr) -> Self {
+    Expr::Assign { name, operator, value: Box::new(value) }
+  }
+  /// creates a new increment or decrement expression, `prefix` tells whether the operator comes
+  /// before the target.
+  ///
+  /// # Returns
+  /// A new update expression.
+  pub fn update(operator: UpdateOperator, target: Expr, prefix: bool) -> Self {
+    Expr::Update { operator, target: Box::new(target), prefix }
+  }
+  /// creates a new lambda expression.
+  ///
+  /// # Returns
+  /// A new lambda expression.
+  pub fn lambda(parameters: Vec<Identifier>, body: Expr) -> Self {
+    Expr::Lambda { parameters, body: Box::new(body) }
+  }
+  /// creates a new call expression.
+  ///
+  /// # Returns
+  /// A new call expression.
+  pub fn call(callee: Expr, arguments: Vec<Expr>) -> Self {
+    Expr::Call { callee: Box::new(callee), arguments }
+  }
+  /// creates a new property access expression, `optional` tells whether it is an optional
+  /// chaining access `object?.name` that short-circuits its enclosing optional chain to nil when
+  /// `object` is nil.
+  ///
+  /// # Returns
+  /// A new property access expression.
+  pub fn get(object: Expr, name: Identifier, optional: bool) -> Self {
+    Expr::Get { object: Box::new(object), name, optional }
+  }
+  /// creates a new optional chain expression, the calls, property accesses and subscripts of
+  /// `chain` are skipped and the whole chain evaluates to nil as soon as one of its optional
+  /// accesses finds nil.
+  ///
+  /// # Returns
+  /// A new optional chain expression.
+  pub fn optional_chain(chain: Expr) -> Self {
+    Expr::OptionalChain(Box::new(chain))
+  }
+  /// creates a new list literal expression.
+  ///
+  /// # Returns
+  /// A new list literal expression.
+  pub fn list(elements: Vec<Expr>) -> Self {
+    Expr::List(elements)
+  }
+  /// creates a new map literal expression, entries are kept in insertion order.
+  ///
+  /// # Returns
+  /// A new map literal expression.
+  pub fn map(entries: Vec<(Expr, Expr)>) -> Self {
+    Expr::Map(entries)
+  }
+  /// creates a new index expression.
+  ///
+  /// # Returns
+  /// A new index expression.
+  pub fn index(object: Expr, index: Expr) -> Self {
+    Expr::Index { object: Box::new(object), index: Box::new(index) }
+  }
+  /// creates a new slice expression, `start` and `end` bounds are optional.
+  ///
+  /// # Returns
+  /// A new slice expression.
+  pub fn slice(object: Expr, start: Option<Expr>, end: Option<Expr>) -> Self {
+    Expr::Slice { object: Box::new(object), start: start.map(Box::new), end: end.map(Box::new) }
+  }
+  /// creates a new index assignment expression.
+  ///
+  /// # Returns
+  /// A new index assignment expression.
+  pub fn index_set(object: Expr, index: Expr, operator: AssignmentOperator, value: Expr) -> Self {
+    Expr::IndexSet {
+      object: Box::new(object),
+      index: Box::new(index),
+      operator,
+      value: Box::new(value),
+    }
+  }
+  /// creates a new property assignment expression.
+  ///
+  /// # Returns
+  /// A new property assignment expression.
+  pub fn set(object: Expr, name: Identifier, operator: AssignmentOperator, value: Expr) -> Self {
+    Expr::Set { object: Box::new(object), name, operator, value: Box::new(value) }
+  }
+}
+
+/// drops an expression without recursing into its children, so that a deep tree like the one built
+/// by a long `1 + 1 + … + 1` doesn't overflow the stack on its way out.
+impl Drop for Expr {
+  fn drop(&mut self) {
+    let mut children = Vec::new();
+    self.take_children(&mut children);
+
+    while let Some(mut child) = children.pop() {
+      child.take_children(&mut children);
+    }
+  }
+}
+
+impl Expr {
+  /// takes this expression out, leaving an empty list in its place.
+  ///
+  /// expressions can't be taken apart by moving out of their fields since they implement `Drop`,
+  /// their children are taken out with this instead.
+  pub fn take(&mut self) -> Expr {
+    std::mem::replace(self, Expr::List(Vec::new()))
+  }
+
+  /// moves the children of this expression onto `children`, leaving empty lists in their place.
+  fn take_children(&mut self, children: &mut Vec<Expr>) {
+    match self {
+      Expr::Literal(_) | Expr::Variable(_) => {}
+      Expr::Grouping(expr)
+      | Expr::Unary { right: expr, .. }
+      | Expr::Assign { value: expr, .. }
+      | Expr::Update { target: expr, .. }
+      | Expr::Lambda { body: expr, .. }
+      | Expr::Get { object: expr, .. }
+      | Expr::OptionalChain(expr) => children.push(expr.take()),
+      Expr::Binary { left, right, .. } => children.extend([left.take(), right.take()]),
+      Expr::Index { object, index } => children.extend([object.take(), index.take()]),
+      Expr::Set { object, value, .. } => children.extend([object.take(), value.take()]),
+      Expr::Ternary { condition, then_branch, else_branch } => {
+        children.extend([condition.take(), then_branch.take(), else_branch.take()]);
+      }
+      Expr::IndexSet { object, index, value, .. } => {
+        children.extend([object.take(), index.take(), value.take()]);
+      }
+      Expr::Slice { object, start, end } => {
+        children.push(object.take());
+        children.extend(start.take().map(|start| *start));
+        children.extend(end.take().map(|end| *end));
+      }
+      Expr::Call { callee, arguments } => {
+        children.push(callee.take());
+        children.append(arguments);
+      }
+      Expr::List(elements) => children.append(elements),
+      Expr::Map(entries) => {
+        children.extend(entries.drain(..).flat_map(|(key, value)| [key, value]));
+      }
+    }
+  }
 }
 
 /// `ExprVisitor` is a trait that defines a visitor pattern for expressions.
@@ -0,0 +1,63 @@
+use std::{fmt::Display, ops::Deref};
+
+use crate::{
+  expr,
+  token::{Token, TokenKind},
+};
+
+use super::Terminal;
+
+#[derive(Debug)]
+pub struct AssignmentOperator(TokenKind);
+
+impl Terminal for AssignmentOperator {
+  fn matches(token: &Token) -> bool {
+    matches!(
+      token.kind,
+      TokenKind::Equal
+        | TokenKind::PlusEqual
+        | TokenKind::MinusEqual
+        | TokenKind::StarEqual
+        | TokenKind::SlashEqual
+        | TokenKind::PercentEqual
+    )
+  }
+}
+
+impl From<AssignmentOperator> for TokenKind {
+  fn from(value: AssignmentOperator) -> Self {
+    value.0
+  }
+}
+
+impl<'src> TryFrom<Token<'src>> for AssignmentOperator {
+  type Error = expr::Error<'src>;
+
+  fn try_from(token: Token<'src>) -> Result<Self, Self::Error> {
+    if <Self as Terminal>::matches(&token) {
+      Ok(AssignmentOperator(token.kind))
+    } else {
+      Err(expr::Error::AssignmentOperator(token))
+    }
+  }
+}
+
+impl AsRef<TokenKind> for AssignmentOperator {
+  fn as_ref(&self) -> &TokenKind {
+    &self.0
+  }
+}
+
+impl Deref for AssignmentOperator {
+  type Target = TokenKind;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl Display for AssignmentOperator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
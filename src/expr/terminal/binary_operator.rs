@@ -3,7 +3,8 @@ use std::{fmt::Display, ops::Deref};
 use crate::token::{Token, TokenKind};
 
 use super::{
-  CommaOperator, Terminal, comparision_operator::ComparisonOperator,
+  BitAndOperator, BitOrOperator, BitXorOperator, CommaOperator, EqualityOperator, NullishOperator,
+  PowerOperator, RangeOperator, ShiftOperator, Terminal, comparision_operator::ComparisonOperator,
   factor_operator::FactorOperator, term_operator::TermOperator,
 };
 
@@ -14,9 +15,17 @@ pub struct BinaryOperator(TokenKind);
 impl Terminal for BinaryOperator {
   fn matches(token: &Token) -> bool {
     CommaOperator::matches(token)
+      || NullishOperator::matches(token)
+      || RangeOperator::matches(token)
+      || EqualityOperator::matches(token)
       || ComparisonOperator::matches(token)
+      || BitOrOperator::matches(token)
+      || BitXorOperator::matches(token)
+      || BitAndOperator::matches(token)
+      || ShiftOperator::matches(token)
       || TermOperator::matches(token)
       || FactorOperator::matches(token)
+      || PowerOperator::matches(token)
   }
 }
 
@@ -52,12 +61,54 @@ impl<'src> TryFrom<Token<'src>> for BinaryOperator {
   }
 }
 
+impl From<NullishOperator> for BinaryOperator {
+  fn from(operator: NullishOperator) -> Self {
+    BinaryOperator(operator.into())
+  }
+}
+
+impl From<RangeOperator> for BinaryOperator {
+  fn from(operator: RangeOperator) -> Self {
+    BinaryOperator(operator.into())
+  }
+}
+
+impl From<EqualityOperator> for BinaryOperator {
+  fn from(operator: EqualityOperator) -> Self {
+    BinaryOperator(operator.into())
+  }
+}
+
 impl From<ComparisonOperator> for BinaryOperator {
   fn from(operator: ComparisonOperator) -> Self {
     BinaryOperator(operator.into())
   }
 }
 
+impl From<BitOrOperator> for BinaryOperator {
+  fn from(operator: BitOrOperator) -> Self {
+    BinaryOperator(operator.into())
+  }
+}
+
+impl From<BitXorOperator> for BinaryOperator {
+  fn from(operator: BitXorOperator) -> Self {
+    BinaryOperator(operator.into())
+  }
+}
+
+impl From<BitAndOperator> for BinaryOperator {
+  fn from(operator: BitAndOperator) -> Self {
+    BinaryOperator(operator.into())
+  }
+}
+
+impl From<ShiftOperator> for BinaryOperator {
+  fn from(operator: ShiftOperator) -> Self {
+    BinaryOperator(operator.into())
+  }
+}
+
 impl From<TermOperator> for BinaryOperator {
   fn from(operator: TermOperator) -> Self {
     BinaryOperator(operator.into())
@@ -70,6 +121,12 @@ impl From<FactorOperator> for BinaryOperator {
   }
 }
 
+impl From<PowerOperator> for BinaryOperator {
+  fn from(operator: PowerOperator) -> Self {
+    BinaryOperator(operator.into())
+  }
+}
+
 impl Display for BinaryOperator {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     write!(f, "{}", self.0)
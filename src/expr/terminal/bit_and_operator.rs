@@ -0,0 +1,55 @@
+use std::{fmt::Display, ops::Deref};
+
+use crate::{
+  expr,
+  token::{Token, TokenKind},
+};
+
+use super::Terminal;
+
+#[derive(Debug)]
+pub struct BitAndOperator(TokenKind);
+
+impl Terminal for BitAndOperator {
+  fn matches(token: &Token) -> bool {
+    matches!(token.kind, TokenKind::Ampersand)
+  }
+}
+
+impl From<BitAndOperator> for TokenKind {
+  fn from(value: BitAndOperator) -> Self {
+    value.0
+  }
+}
+
+impl AsRef<TokenKind> for BitAndOperator {
+  fn as_ref(&self) -> &TokenKind {
+    &self.0
+  }
+}
+
+impl Deref for BitAndOperator {
+  type Target = TokenKind;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl<'src> TryFrom<Token<'src>> for BitAndOperator {
+  type Error = expr::Error<'src>;
+
+  fn try_from(token: Token<'src>) -> Result<Self, Self::Error> {
+    if <BitAndOperator as Terminal>::matches(&token) {
+      Ok(BitAndOperator(token.kind))
+    } else {
+      Err(expr::Error::BitAndOperator(token))
+    }
+  }
+}
+
+impl Display for BitAndOperator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
@@ -0,0 +1,55 @@
+use std::{fmt::Display, ops::Deref};
+
+use crate::{
+  expr,
+  token::{Token, TokenKind},
+};
+
+use super::Terminal;
+
+#[derive(Debug)]
+pub struct BitOrOperator(TokenKind);
+
+impl Terminal for BitOrOperator {
+  fn matches(token: &Token) -> bool {
+    matches!(token.kind, TokenKind::Pipe)
+  }
+}
+
+impl From<BitOrOperator> for TokenKind {
+  fn from(value: BitOrOperator) -> Self {
+    value.0
+  }
+}
+
+impl AsRef<TokenKind> for BitOrOperator {
+  fn as_ref(&self) -> &TokenKind {
+    &self.0
+  }
+}
+
+impl Deref for BitOrOperator {
+  type Target = TokenKind;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl<'src> TryFrom<Token<'src>> for BitOrOperator {
+  type Error = expr::Error<'src>;
+
+  fn try_from(token: Token<'src>) -> Result<Self, Self::Error> {
+    if <BitOrOperator as Terminal>::matches(&token) {
+      Ok(BitOrOperator(token.kind))
+    } else {
+      Err(expr::Error::BitOrOperator(token))
+    }
+  }
+}
+
+impl Display for BitOrOperator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
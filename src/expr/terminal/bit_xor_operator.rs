@@ -0,0 +1,55 @@
+use std::{fmt::Display, ops::Deref};
+
+use crate::{
+  expr,
+  token::{Token, TokenKind},
+};
+
+use super::Terminal;
+
+#[derive(Debug)]
+pub struct BitXorOperator(TokenKind);
+
+impl Terminal for BitXorOperator {
+  fn matches(token: &Token) -> bool {
+    matches!(token.kind, TokenKind::Caret)
+  }
+}
+
+impl From<BitXorOperator> for TokenKind {
+  fn from(value: BitXorOperator) -> Self {
+    value.0
+  }
+}
+
+impl AsRef<TokenKind> for BitXorOperator {
+  fn as_ref(&self) -> &TokenKind {
+    &self.0
+  }
+}
+
+impl Deref for BitXorOperator {
+  type Target = TokenKind;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl<'src> TryFrom<Token<'src>> for BitXorOperator {
+  type Error = expr::Error<'src>;
+
+  fn try_from(token: Token<'src>) -> Result<Self, Self::Error> {
+    if <BitXorOperator as Terminal>::matches(&token) {
+      Ok(BitXorOperator(token.kind))
+    } else {
+      Err(expr::Error::BitXorOperator(token))
+    }
+  }
+}
+
+impl Display for BitXorOperator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
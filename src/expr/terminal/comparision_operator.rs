@@ -14,12 +14,7 @@ impl Terminal for ComparisonOperator {
   fn matches(token: &Token) -> bool {
     matches!(
       token.kind,
-      TokenKind::BangEqual
-        | TokenKind::EqualEqual
-        | TokenKind::Less
-        | TokenKind::LessEqual
-        | TokenKind::Greater
-        | TokenKind::GreaterEqual
+      TokenKind::Less | TokenKind::LessEqual | TokenKind::Greater | TokenKind::GreaterEqual
     )
   }
 }
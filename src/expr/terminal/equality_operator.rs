@@ -0,0 +1,55 @@
+use std::{fmt::Display, ops::Deref};
+
+use crate::{
+  expr,
+  token::{Token, TokenKind},
+};
+
+use super::Terminal;
+
+#[derive(Debug)]
+pub struct EqualityOperator(TokenKind);
+
+impl Terminal for EqualityOperator {
+  fn matches(token: &Token) -> bool {
+    matches!(token.kind, TokenKind::BangEqual | TokenKind::EqualEqual)
+  }
+}
+
+impl From<EqualityOperator> for TokenKind {
+  fn from(value: EqualityOperator) -> Self {
+    value.0
+  }
+}
+
+impl AsRef<TokenKind> for EqualityOperator {
+  fn as_ref(&self) -> &TokenKind {
+    &self.0
+  }
+}
+
+impl Deref for EqualityOperator {
+  type Target = TokenKind;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl<'src> TryFrom<Token<'src>> for EqualityOperator {
+  type Error = expr::Error<'src>;
+
+  fn try_from(token: Token<'src>) -> Result<Self, Self::Error> {
+    if <EqualityOperator as Terminal>::matches(&token) {
+      Ok(EqualityOperator(token.kind))
+    } else {
+      Err(expr::Error::EqualityOperator(token))
+    }
+  }
+}
+
+impl Display for EqualityOperator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
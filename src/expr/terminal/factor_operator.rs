@@ -12,7 +12,7 @@ pub struct FactorOperator(TokenKind);
 
 impl Terminal for FactorOperator {
   fn matches(token: &Token) -> bool {
-    matches!(token.kind, TokenKind::Star | TokenKind::Slash)
+    matches!(token.kind, TokenKind::Star | TokenKind::Slash | TokenKind::Percent)
   }
 }
 
@@ -0,0 +1,70 @@
+use std::{fmt::Display, ops::Deref};
+
+use crate::{
+  expr,
+  token::{Token, TokenKind},
+};
+
+use super::Terminal;
+
+/// represents an identifier token along with its name.
+///
+/// the token kind is always [`TokenKind::Identifier`] so only the name is kept, as a boxed `str`.
+/// this keeps [`Expr`](crate::expr::Expr) small, which the parser moves around on every level of
+/// the grammar.
+#[derive(Debug, Default)]
+pub struct Identifier {
+  name: Box<str>,
+}
+
+impl Identifier {
+  /// name of the identifier as written in the source code.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+}
+
+impl Terminal for Identifier {
+  fn matches(token: &Token) -> bool {
+    matches!(token.kind, TokenKind::Identifier)
+  }
+}
+
+impl From<Identifier> for TokenKind {
+  fn from(_: Identifier) -> Self {
+    TokenKind::Identifier
+  }
+}
+
+impl AsRef<TokenKind> for Identifier {
+  fn as_ref(&self) -> &TokenKind {
+    &TokenKind::Identifier
+  }
+}
+
+impl Deref for Identifier {
+  type Target = TokenKind;
+
+  fn deref(&self) -> &Self::Target {
+    &TokenKind::Identifier
+  }
+}
+
+impl<'src> TryFrom<Token<'src>> for Identifier {
+  type Error = expr::Error<'src>;
+
+  fn try_from(token: Token<'src>) -> Result<Self, Self::Error> {
+    if <Self as Terminal>::matches(&token) {
+      let name = token.lexeme.expect("identifier tokens always have a lexeme").into();
+      Ok(Identifier { name })
+    } else {
+      Err(expr::Error::Identifier(token))
+    }
+  }
+}
+
+impl Display for Identifier {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.name)
+  }
+}
@@ -1,12 +1,23 @@
+mod assignment_operator;
 mod binary_operator;
+mod bit_and_operator;
+mod bit_or_operator;
+mod bit_xor_operator;
 mod comma_operator;
 mod comparision_operator;
+mod equality_operator;
 mod factor_operator;
+mod identifier;
 mod literal;
+mod nullish_operator;
+mod power_operator;
+mod range_operator;
+mod shift_operator;
 mod term_operator;
 mod ternary_else_operator;
 mod ternary_then_operator;
 mod unary_operator;
+mod update_operator;
 
 use std::{
   fmt::{Debug, Display},
@@ -16,10 +27,15 @@ use std::{
 use crate::token::{Token, TokenKind};
 
 pub use self::{
-  binary_operator::BinaryOperator, comma_operator::CommaOperator,
-  comparision_operator::ComparisonOperator, factor_operator::FactorOperator, literal::Literal,
-  term_operator::TermOperator, ternary_else_operator::TernaryElseOperator,
-  ternary_then_operator::TernaryThenOperator, unary_operator::UnaryOperator,
+  assignment_operator::AssignmentOperator, binary_operator::BinaryOperator,
+  bit_and_operator::BitAndOperator, bit_or_operator::BitOrOperator,
+  bit_xor_operator::BitXorOperator, comma_operator::CommaOperator,
+  comparision_operator::ComparisonOperator, equality_operator::EqualityOperator,
+  factor_operator::FactorOperator, identifier::Identifier, literal::Literal,
+  nullish_operator::NullishOperator, power_operator::PowerOperator, range_operator::RangeOperator,
+  shift_operator::ShiftOperator, term_operator::TermOperator,
+  ternary_else_operator::TernaryElseOperator, ternary_then_operator::TernaryThenOperator,
+  unary_operator::UnaryOperator, update_operator::UpdateOperator,
 };
 
 pub trait Terminal:
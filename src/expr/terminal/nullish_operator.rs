@@ -0,0 +1,55 @@
+use std::{fmt::Display, ops::Deref};
+
+use crate::{
+  expr,
+  token::{Token, TokenKind},
+};
+
+use super::Terminal;
+
+#[derive(Debug)]
+pub struct NullishOperator(TokenKind);
+
+impl Terminal for NullishOperator {
+  fn matches(token: &Token) -> bool {
+    matches!(token.kind, TokenKind::QuestionQuestion)
+  }
+}
+
+impl From<NullishOperator> for TokenKind {
+  fn from(value: NullishOperator) -> Self {
+    value.0
+  }
+}
+
+impl<'src> TryFrom<Token<'src>> for NullishOperator {
+  type Error = expr::Error<'src>;
+
+  fn try_from(token: Token<'src>) -> Result<Self, Self::Error> {
+    if <Self as Terminal>::matches(&token) {
+      Ok(NullishOperator(token.kind))
+    } else {
+      Err(expr::Error::NullishOperator(token))
+    }
+  }
+}
+
+impl AsRef<TokenKind> for NullishOperator {
+  fn as_ref(&self) -> &TokenKind {
+    &self.0
+  }
+}
+
+impl Deref for NullishOperator {
+  type Target = TokenKind;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl Display for NullishOperator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
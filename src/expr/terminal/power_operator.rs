@@ -0,0 +1,55 @@
+use std::{fmt::Display, ops::Deref};
+
+use crate::{
+  expr,
+  token::{Token, TokenKind},
+};
+
+use super::Terminal;
+
+#[derive(Debug)]
+pub struct PowerOperator(TokenKind);
+
+impl Terminal for PowerOperator {
+  fn matches(token: &Token) -> bool {
+    matches!(token.kind, TokenKind::StarStar)
+  }
+}
+
+impl From<PowerOperator> for TokenKind {
+  fn from(value: PowerOperator) -> Self {
+    value.0
+  }
+}
+
+impl AsRef<TokenKind> for PowerOperator {
+  fn as_ref(&self) -> &TokenKind {
+    &self.0
+  }
+}
+
+impl Deref for PowerOperator {
+  type Target = TokenKind;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl<'src> TryFrom<Token<'src>> for PowerOperator {
+  type Error = expr::Error<'src>;
+
+  fn try_from(token: Token<'src>) -> Result<Self, Self::Error> {
+    if <PowerOperator as Terminal>::matches(&token) {
+      Ok(PowerOperator(token.kind))
+    } else {
+      Err(expr::Error::PowerOperator(token))
+    }
+  }
+}
+
+impl Display for PowerOperator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
@@ -0,0 +1,55 @@
+use std::{fmt::Display, ops::Deref};
+
+use crate::{
+  expr,
+  token::{Token, TokenKind},
+};
+
+use super::Terminal;
+
+#[derive(Debug)]
+pub struct RangeOperator(TokenKind);
+
+impl Terminal for RangeOperator {
+  fn matches(token: &Token) -> bool {
+    matches!(token.kind, TokenKind::DotDot | TokenKind::DotDotEqual)
+  }
+}
+
+impl From<RangeOperator> for TokenKind {
+  fn from(value: RangeOperator) -> Self {
+    value.0
+  }
+}
+
+impl<'src> TryFrom<Token<'src>> for RangeOperator {
+  type Error = expr::Error<'src>;
+
+  fn try_from(token: Token<'src>) -> Result<Self, Self::Error> {
+    if <Self as Terminal>::matches(&token) {
+      Ok(RangeOperator(token.kind))
+    } else {
+      Err(expr::Error::RangeOperator(token))
+    }
+  }
+}
+
+impl AsRef<TokenKind> for RangeOperator {
+  fn as_ref(&self) -> &TokenKind {
+    &self.0
+  }
+}
+
+impl Deref for RangeOperator {
+  type Target = TokenKind;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl Display for RangeOperator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
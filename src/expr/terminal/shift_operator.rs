@@ -0,0 +1,55 @@
+use std::{fmt::Display, ops::Deref};
+
+use crate::{
+  expr,
+  token::{Token, TokenKind},
+};
+
+use super::Terminal;
+
+#[derive(Debug)]
+pub struct ShiftOperator(TokenKind);
+
+impl Terminal for ShiftOperator {
+  fn matches(token: &Token) -> bool {
+    matches!(token.kind, TokenKind::LessLess | TokenKind::GreaterGreater)
+  }
+}
+
+impl From<ShiftOperator> for TokenKind {
+  fn from(value: ShiftOperator) -> Self {
+    value.0
+  }
+}
+
+impl AsRef<TokenKind> for ShiftOperator {
+  fn as_ref(&self) -> &TokenKind {
+    &self.0
+  }
+}
+
+impl Deref for ShiftOperator {
+  type Target = TokenKind;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl<'src> TryFrom<Token<'src>> for ShiftOperator {
+  type Error = expr::Error<'src>;
+
+  fn try_from(token: Token<'src>) -> Result<Self, Self::Error> {
+    if <ShiftOperator as Terminal>::matches(&token) {
+      Ok(ShiftOperator(token.kind))
+    } else {
+      Err(expr::Error::ShiftOperator(token))
+    }
+  }
+}
+
+impl Display for ShiftOperator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
@@ -13,7 +13,7 @@ pub struct UnaryOperator(TokenKind);
 
 impl Terminal for UnaryOperator {
   fn matches(token: &Token) -> bool {
-    matches!(token.kind, TokenKind::Bang | TokenKind::Minus)
+    matches!(token.kind, TokenKind::Bang | TokenKind::Minus | TokenKind::Tilde)
   }
 }
 
@@ -0,0 +1,55 @@
+use std::{fmt::Display, ops::Deref};
+
+use crate::{
+  expr,
+  token::{Token, TokenKind},
+};
+
+use super::Terminal;
+
+#[derive(Debug)]
+pub struct UpdateOperator(TokenKind);
+
+impl Terminal for UpdateOperator {
+  fn matches(token: &Token) -> bool {
+    matches!(token.kind, TokenKind::PlusPlus | TokenKind::MinusMinus)
+  }
+}
+
+impl From<UpdateOperator> for TokenKind {
+  fn from(value: UpdateOperator) -> Self {
+    value.0
+  }
+}
+
+impl<'src> TryFrom<Token<'src>> for UpdateOperator {
+  type Error = expr::Error<'src>;
+
+  fn try_from(token: Token<'src>) -> Result<Self, Self::Error> {
+    if <Self as Terminal>::matches(&token) {
+      Ok(UpdateOperator(token.kind))
+    } else {
+      Err(expr::Error::UpdateOperator(token))
+    }
+  }
+}
+
+impl AsRef<TokenKind> for UpdateOperator {
+  fn as_ref(&self) -> &TokenKind {
+    &self.0
+  }
+}
+
+impl Deref for UpdateOperator {
+  type Target = TokenKind;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl Display for UpdateOperator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
@@ -0,0 +1,118 @@
+use crate::{
+  expr::{AssignmentOperator, Expr, Terminal},
+  token::Token,
+};
+
+use super::{Parser, macros::expr_or_return};
+
+impl<'src, I> Parser<'src, I>
+where
+  I: Iterator<Item = Token<'src>>,
+{
+  /// parses an assignment expression, either plain `=` or compound like `+=`.
+  ///
+  /// assignment has right-to-left associativity, variables, properties and index expressions are
+  /// the only valid targets. compound assignments keep their target as a single node instead of being
+  /// desugared, so that `a[f()] += 1` evaluates `a[f()]` only once.
+  pub(super) fn assignment(&mut self) -> Option<super::Result<'src>> {
+    let mut expr = expr_or_return!(self.ternary());
+
+    // the targets are collected in a loop and assigned from the right, so that a long chain of
+    // `a = b = …` doesn't nest.
+    let mut targets = Vec::new();
+    while let Some(operator) = self.tokens.next_if(|t| AssignmentOperator::matches(t)) {
+      let value = match self.ternary() {
+        Some(Ok(value)) => value,
+        None => {
+          return Some(Err(super::Error::AssignmentMissingValue { target: expr, operator }.into()));
+        }
+        err => return err,
+      };
+      targets.push((expr, operator));
+      expr = value;
+    }
+
+    let value = targets
+      .into_iter()
+      .rev()
+      .try_fold(expr, |value, (target, operator)| assign(target, operator, value));
+
+    Some(value)
+  }
+}
+
+/// assigns `value` to `target` with the assignment `operator`.
+fn assign<'src>(mut target: Expr, operator: Token<'src>, value: Expr) -> super::Result<'src> {
+  match &mut target {
+    Expr::Variable(name) => {
+      Ok(Expr::assign(std::mem::take(name), operator.try_into().unwrap(), value))
+    }
+    Expr::Index { object, index } => {
+      Ok(Expr::index_set(object.take(), index.take(), operator.try_into().unwrap(), value))
+    }
+    Expr::Get { object, name, optional: false } => {
+      Ok(Expr::set(object.take(), std::mem::take(name), operator.try_into().unwrap(), value))
+    }
+    _ => Err(super::Error::InvalidAssignmentTarget { target, operator }.into()),
+  }
+}
+
+/// whether `expr` can be the target of an assignment or an increment/decrement.
+///
+/// optional chaining accesses `obj?.name` can't be assigned to, as there is nothing to assign when
+/// `obj` is nil.
+pub(super) fn is_assignable(expr: &Expr) -> bool {
+  matches!(expr, Expr::Variable(_) | Expr::Index { .. } | Expr::Get { optional: false, .. })
+}
+
+#[cfg(test)]
+mod tests {
+  use claims::assert_matches;
+  use rstest::rstest;
+
+  use crate::parser::{
+    Error,
+    tests::{parse, parse_err},
+  };
+
+  #[rstest(src, expected)]
+  #[case::index("xs[0] = 1", "(= (index xs 0) 1)")]
+  #[case::right_associative("xs[0] = ys[1] = 2", "(= (index xs 0) (= (index ys 1) 2))")]
+  #[case::nested_index("xs[0][1] = 2", "(= (index (index xs 0) 1) 2)")]
+  #[case::variable("x = 1", "(= x 1)")]
+  #[case::variable_chain("x = y = 1", "(= x (= y 1))")]
+  #[case::add("x += 1", "(+= x 1)")]
+  #[case::subtract("x -= 1", "(-= x 1)")]
+  #[case::multiply("x *= 2", "(*= x 2)")]
+  #[case::divide("x /= 2", "(/= x 2)")]
+  #[case::modulo("x %= 2", "(%= x 2)")]
+  #[case::compound_index("xs[f] += 1", "(+= (index xs f) 1)")]
+  #[case::compound_chain("x += y -= 1", "(+= x (-= y 1))")]
+  #[case::compound_value("x *= y + 1", "(*= x (+ y 1))")]
+  #[case::property("obj.f = 1", "(= (. obj f) 1)")]
+  #[case::compound_property("obj.f += 1", "(+= (. obj f) 1)")]
+  #[case::nested_property("a.b.c = 1", "(= (. (. a b) c) 1)")]
+  #[case::property_of_index("xs[0].f = 1", "(= (. (index xs 0) f) 1)")]
+  #[case::property_of_call("f().g = 1", "(= (. (call f) g) 1)")]
+  #[case::property_chain("a.f = b.g = 1", "(= (. a f) (= (. b g) 1))")]
+  fn parser_assignment_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+
+  #[rstest(src)]
+  #[case::literal("1 = 1")]
+  #[case::slice("xs[1:2] = 1")]
+  #[case::grouping("(x) = 1")]
+  #[case::compound_literal("1 += 1")]
+  #[case::update("x++ = 1")]
+  #[case::call("f() = 1")]
+  #[case::optional_property("obj?.f = 1")]
+  fn parser_assignment_invalid_target_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::InvalidAssignmentTarget { .. });
+  }
+
+  #[test]
+  fn parser_assignment_missing_value_err() {
+    assert_matches!(*parse_err("xs[0] +="), Error::AssignmentMissingValue { .. });
+  }
+}
@@ -0,0 +1,57 @@
+use super::macros::expr_or_return;
+use crate::expr::{BitXorOperator, Expr, Terminal};
+use crate::parser::Parser;
+use crate::token::Token;
+
+impl<'src, I> Parser<'src, I>
+where
+  I: Iterator<Item = Token<'src>>,
+{
+  /// parse a bitwise xor expression.
+  ///
+  /// bitwise xor has left-to-right associativity.
+  pub(super) fn bit_xor(&mut self) -> Option<super::Result<'src>> {
+    let mut expr = expr_or_return!(self.bit_and());
+
+    while let Some(operator) = self.tokens.next_if(|t| BitXorOperator::matches(t)) {
+      let right = match self.bit_and() {
+        Some(Ok(right)) => right,
+        None => {
+          return Some(Err(super::Error::BitXorMissingRight { left: expr, operator }.into()));
+        }
+        err => return err,
+      };
+      let operator: BitXorOperator =
+        operator.try_into().expect("Bitwise xor operators are binary operators");
+      expr = Expr::binary(expr, operator.into(), right);
+    }
+
+    Some(Ok(expr))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use claims::assert_matches;
+  use rstest::rstest;
+
+  use crate::parser::{
+    Error,
+    tests::{parse, parse_err},
+  };
+
+  #[rstest(src, expected)]
+  #[case::single("a ^ b", "(^ a b)")]
+  #[case::left_associative("a ^ b ^ c", "(^ (^ a b) c)")]
+  fn parser_bit_xor_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+
+  #[rstest(src)]
+  #[case::end("a ^")]
+  #[case::chained("a ^ b ^")]
+  #[case::before_closing_paren("(a ^)")]
+  fn parser_bit_xor_missing_right_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::BitXorMissingRight { .. });
+  }
+}
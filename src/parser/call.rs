@@ -0,0 +1,220 @@
+use crate::{
+  expr::{Expr, Identifier, Terminal, UpdateOperator},
+  token::{Token, TokenKind},
+};
+
+use super::{Parser, assignment::is_assignable, macros::expr_or_return};
+
+impl<'src, I> Parser<'src, I>
+where
+  I: Iterator<Item = Token<'src>>,
+{
+  /// parses a call expression.
+  ///
+  /// a primary expression followed by any number of calls `f(a, b)`, property accesses `obj.name`,
+  /// optional chaining accesses `obj?.name` and subscripts, either an index `xs[i]` or a slice
+  /// `xs[start:end]` whose bounds are optional. these have left-to-right associativity, and can be
+  /// followed by a postfix increment `x++` or decrement `x--`.
+  ///
+  /// a chain containing an optional access is wrapped in an optional chain, so that `a?.b.c()`
+  /// short-circuits the access to `c` and the call as well when `a` is nil.
+  pub(super) fn call(&mut self) -> Option<super::Result<'src>> {
+    let mut expr = expr_or_return!(self.primary());
+    let mut optional = false;
+
+    while let Some(token) = self.tokens.next_if(|t| {
+      matches!(
+        t.kind,
+        TokenKind::LeftParen | TokenKind::LeftBracket | TokenKind::Dot | TokenKind::QuestionDot
+      )
+    }) {
+      expr = match token.kind {
+        TokenKind::LeftParen => {
+          expr_or_return!(self.nested(token, |parser, token| parser.arguments(expr, token)))
+        }
+        TokenKind::LeftBracket => {
+          expr_or_return!(self.nested(token, |parser, token| parser.subscript(expr, token)))
+        }
+        _ => {
+          optional |= token.kind == TokenKind::QuestionDot;
+          expr_or_return!(self.property(expr, token))
+        }
+      };
+    }
+
+    if optional {
+      expr = Expr::optional_chain(expr);
+    }
+
+    if let Some(operator) = self.tokens.next_if(|t| UpdateOperator::matches(t)) {
+      if !is_assignable(&expr) {
+        return Some(Err(super::Error::InvalidUpdateTarget { target: expr, operator }.into()));
+      }
+
+      expr = Expr::update(operator.try_into().unwrap(), expr, false);
+    }
+
+    Some(Ok(expr))
+  }
+
+  /// parses the arguments of a call to `callee`, after its opening `(`.
+  ///
+  /// arguments are separated by commas, a trailing comma is allowed.
+  fn arguments(&mut self, callee: Expr, left_paren: Token<'src>) -> Option<super::Result<'src>> {
+    let mut arguments = Vec::new();
+
+    loop {
+      if self.tokens.next_if(|t| t.kind == TokenKind::RightParen).is_some() {
+        return Some(Ok(Expr::call(callee, arguments)));
+      }
+
+      match self.assignment() {
+        Some(Ok(argument)) => arguments.push(argument),
+        None => {
+          return Some(Err(super::Error::CallNotClosed { left_paren, callee, arguments }.into()));
+        }
+        err => return err,
+      }
+
+      if self.tokens.next_if(|t| t.kind == TokenKind::Comma).is_none() {
+        return match self.tokens.next_if(|t| t.kind == TokenKind::RightParen) {
+          Some(_) => Some(Ok(Expr::call(callee, arguments))),
+          None => Some(Err(super::Error::CallNotClosed { left_paren, callee, arguments }.into())),
+        };
+      }
+    }
+  }
+
+  /// parses the property name of an access to `object`, after its `.` or `?.`.
+  fn property(&mut self, object: Expr, dot: Token<'src>) -> Option<super::Result<'src>> {
+    let optional = dot.kind == TokenKind::QuestionDot;
+
+    match self.tokens.next_if(|t| Identifier::matches(t)) {
+      Some(name) => Some(Ok(Expr::get(object, name.try_into().unwrap(), optional))),
+      None => Some(Err(super::Error::GetMissingName { dot, object }.into())),
+    }
+  }
+
+  /// parses the rest of a subscript of `object`, after its opening `[`.
+  fn subscript(&mut self, object: Expr, left_bracket: Token<'src>) -> Option<super::Result<'src>> {
+    let start = match self.tokens.peek() {
+      Some(t) if t.kind == TokenKind::Colon => None,
+      _ => match self.assignment() {
+        Some(Ok(start)) => Some(start),
+        None => {
+          return Some(Err(super::Error::IndexMissingExpression { left_bracket, object }.into()));
+        }
+        err => return err,
+      },
+    };
+
+    if self.tokens.next_if(|t| t.kind == TokenKind::Colon).is_none() {
+      let index = start.expect("subscripts without a colon start with an index");
+      return match self.tokens.next_if(|t| t.kind == TokenKind::RightBracket) {
+        Some(_) => Some(Ok(Expr::index(object, index))),
+        None => Some(Err(super::Error::IndexNotClosed { left_bracket, object }.into())),
+      };
+    }
+
+    let end = match self.tokens.peek() {
+      Some(t) if t.kind == TokenKind::RightBracket => None,
+      _ => match self.assignment() {
+        Some(Ok(end)) => Some(end),
+        None => return Some(Err(super::Error::IndexNotClosed { left_bracket, object }.into())),
+        err => return err,
+      },
+    };
+
+    match self.tokens.next_if(|t| t.kind == TokenKind::RightBracket) {
+      Some(_) => Some(Ok(Expr::slice(object, start, end))),
+      None => Some(Err(super::Error::IndexNotClosed { left_bracket, object }.into())),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use claims::assert_matches;
+  use rstest::rstest;
+
+  use crate::parser::{
+    Error,
+    tests::{parse, parse_err},
+  };
+
+  #[rstest(src, expected)]
+  #[case::variable("xs", "xs")]
+  #[case::index("xs[0]", "(index xs 0)")]
+  #[case::negative_index("xs[-1]", "(index xs (- 1))")]
+  #[case::chained_index("xs[0][1]", "(index (index xs 0) 1)")]
+  #[case::expression_index("xs[i + 1]", "(index xs (+ i 1))")]
+  #[case::ternary_index("xs[a ? 1 : 2]", "(index xs (? a 1 2))")]
+  #[case::slice("xs[1:3]", "(slice xs 1 3)")]
+  #[case::slice_without_start("xs[:3]", "(slice xs _ 3)")]
+  #[case::slice_without_end("xs[1:]", "(slice xs 1 _)")]
+  #[case::slice_without_bounds("xs[:]", "(slice xs _ _)")]
+  #[case::index_of_slice("xs[1:][0]", "(index (slice xs 1 _) 0)")]
+  #[case::call("f()", "(call f)")]
+  #[case::call_arguments("f(a, 1 + 2)", "(call f a (+ 1 2))")]
+  #[case::call_trailing_comma("f(a,)", "(call f a)")]
+  #[case::call_grouped_comma("f((a, b))", "(call f (group (, a b)))")]
+  #[case::chained_calls("f(1)(2)", "(call (call f 1) 2)")]
+  #[case::get("obj.field", "(. obj field)")]
+  #[case::chained_get("a.b.c", "(. (. a b) c)")]
+  #[case::method_call("obj.method(1)", "(call (. obj method) 1)")]
+  #[case::optional_get("obj?.field", "(chain (?. obj field))")]
+  #[case::optional_method_call("obj?.method()", "(chain (call (?. obj method)))")]
+  #[case::optional_then_get("a?.b.c", "(chain (. (?. a b) c))")]
+  #[case::optional_then_index("a?.b[0]", "(chain (index (?. a b) 0))")]
+  #[case::optional_twice("a?.b?.c", "(chain (?. (?. a b) c))")]
+  #[case::get_then_optional("a.b?.c", "(chain (?. (. a b) c))")]
+  #[case::grouping_ends_chain("(a?.b).c", "(. (group (chain (?. a b))) c)")]
+  #[case::chain_in_argument("f(a?.b).c", "(. (call f (chain (?. a b))) c)")]
+  #[case::index_get_call("xs[0].f(1)[2]", "(index (call (. (index xs 0) f) 1) 2)")]
+  #[case::increment("x++", "(post++ x)")]
+  #[case::decrement("x--", "(post-- x)")]
+  #[case::increment_index("xs[0]++", "(post++ (index xs 0))")]
+  #[case::increment_property("obj.f++", "(post++ (. obj f))")]
+  #[case::decrement_property_of_index("xs[0].f--", "(post-- (. (index xs 0) f))")]
+  fn parser_call_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+
+  #[test]
+  fn parser_call_index_missing_expression_err() {
+    assert_matches!(*parse_err("xs[]"), Error::IndexMissingExpression { .. });
+  }
+
+  #[rstest(src)]
+  #[case::literal("1++")]
+  #[case::grouping("(x)--")]
+  #[case::slice("xs[1:]++")]
+  #[case::optional_property("obj?.f++")]
+  fn parser_postfix_update_invalid_target_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::InvalidUpdateTarget { .. });
+  }
+
+  #[rstest(src)]
+  #[case::empty("f(")]
+  #[case::missing_comma("f(a b)")]
+  #[case::missing_argument("f(a, , b)")]
+  fn parser_call_not_closed_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::CallNotClosed { .. });
+  }
+
+  #[rstest(src)]
+  #[case::get("obj.")]
+  #[case::optional_get("obj?.")]
+  #[case::number("obj.1")]
+  fn parser_call_get_missing_name_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::GetMissingName { .. });
+  }
+
+  #[rstest(src)]
+  #[case::index("xs[0")]
+  #[case::slice("xs[0:1")]
+  #[case::slice_without_end("xs[0:")]
+  fn parser_call_index_not_closed_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::IndexNotClosed { .. });
+  }
+}
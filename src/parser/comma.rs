@@ -14,12 +14,12 @@ where
   ///
   /// comma expressions have the lowest precedence with left-to-right associativity.
   pub(super) fn comma(&mut self) -> Option<super::Result<'src>> {
-    let mut expr = expr_or_return!(self.ternary());
+    let mut expr = expr_or_return!(self.assignment());
 
     while let Some(operator) =
       self.tokens.next_if(|t| CommaOperator::matches(t)).map(|t| t.try_into().unwrap())
     {
-      let right = expr_or_return!(self.ternary());
+      let right = expr_or_return!(self.assignment());
       expr = Expr::binary(expr, operator, right);
     }
 
@@ -30,6 +30,7 @@ where
 #[cfg(test)]
 mod tests {
   use claims::{assert_matches, assert_ok, assert_some};
+  use rstest::rstest;
 
   use crate::{
     expr::{AstFormatter, ExprVisitor},
@@ -37,6 +38,7 @@ mod tests {
   };
 
   use super::*;
+  use crate::parser::tests::parse;
 
   #[test]
   fn parser_comma_ok() {
@@ -79,4 +81,11 @@ mod tests {
     assert_matches!(expr, Expr::Binary { left: _, operator: _, right: _ });
     println!("{:?}", AstFormatter.visit(&expr));
   }
+
+  #[rstest(src, expected)]
+  #[case::single("a, b", "(, a b)")]
+  #[case::left_associative("a, b, c", "(, (, a b) c)")]
+  fn parser_comma_table_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
 }
@@ -11,12 +11,12 @@ where
   ///
   /// comparison has left-to-right associativity.
   pub(super) fn comparison(&mut self) -> Option<super::Result<'src>> {
-    let mut expr = expr_or_return!(self.term());
+    let mut expr = expr_or_return!(self.bit_or());
 
     while let Some(operator) = self.tokens.next_if(|t| ComparisonOperator::matches(t)).map(|t| {
       TryInto::<ComparisonOperator>::try_into(t).expect("comparison operators are binary operators")
     }) {
-      let right = expr_or_return!(self.term());
+      let right = expr_or_return!(self.bit_or());
       expr = Expr::binary(expr, operator.into(), right);
     }
 
@@ -27,6 +27,7 @@ where
 #[cfg(test)]
 mod tests {
   use claims::{assert_matches, assert_ok, assert_some};
+  use rstest::rstest;
 
   use crate::{
     expr::{AstFormatter, ExprVisitor},
@@ -34,6 +35,7 @@ mod tests {
   };
 
   use super::*;
+  use crate::parser::tests::parse;
 
   #[test]
   fn parser_comparison_ok() {
@@ -55,4 +57,14 @@ mod tests {
     assert_matches!(expr, Expr::Binary { left: _, operator: _, right: _ });
     println!("{:?}", AstFormatter.visit(&expr));
   }
+
+  #[rstest(src, expected)]
+  #[case::less("a < b", "(< a b)")]
+  #[case::less_equal("a <= b", "(<= a b)")]
+  #[case::greater("a > b", "(> a b)")]
+  #[case::greater_equal("a >= b", "(>= a b)")]
+  #[case::left_associative("a < b > c", "(> (< a b) c)")]
+  fn parser_comparison_table_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
 }
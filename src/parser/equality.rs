@@ -0,0 +1,40 @@
+use super::macros::expr_or_return;
+use crate::expr::{EqualityOperator, Expr, Terminal};
+use crate::parser::Parser;
+use crate::token::Token;
+
+impl<'src, I> Parser<'src, I>
+where
+  I: Iterator<Item = Token<'src>>,
+{
+  /// parse an equality expression.
+  ///
+  /// equality has left-to-right associativity.
+  pub(super) fn equality(&mut self) -> Option<super::Result<'src>> {
+    let mut expr = expr_or_return!(self.comparison());
+
+    while let Some(operator) = self.tokens.next_if(|t| EqualityOperator::matches(t)).map(|t| {
+      TryInto::<EqualityOperator>::try_into(t).expect("equality operators are binary operators")
+    }) {
+      let right = expr_or_return!(self.comparison());
+      expr = Expr::binary(expr, operator.into(), right);
+    }
+
+    Some(Ok(expr))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use crate::parser::tests::parse;
+
+  #[rstest(src, expected)]
+  #[case::equal("a == b", "(== a b)")]
+  #[case::not_equal("a != b", "(!= a b)")]
+  #[case::left_associative("a == b != c", "(!= (== a b) c)")]
+  fn parser_equality_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+}
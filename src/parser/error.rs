@@ -1,14 +1,18 @@
 use thiserror::Error;
 
-use crate::expr::Expr;
+use crate::expr::{Expr, Identifier};
 use crate::token::Token;
 
-pub type Result<'src> = core::result::Result<Expr, Error<'src>>;
+/// errors are boxed since they carry the partially parsed expressions, every level of the grammar
+/// passes results around by value and keeping them small keeps each nesting level's stack small.
+pub type Result<'src, T = Expr> = core::result::Result<T, Box<Error<'src>>>;
 
 #[derive(Debug, Error)]
 pub enum Error<'src> {
   #[error("Expected ')' after expression")]
   GroupingNotClosed { right_paren: Token<'src>, expr: Expr },
+  #[error("Expression nested deeper than {} levels", super::MAX_NESTING_DEPTH)]
+  NestingTooDeep { token: Token<'src> },
   #[error("Expected expression after '('")]
   GroupingMissingExpression { right_paren: Token<'src> },
   #[error("Expected expression '?'")]
@@ -22,4 +26,48 @@ pub enum Error<'src> {
     then_branch: Expr,
     else_operator: Token<'src>,
   },
+  #[error("Expected comma separated parameter names before '=>'")]
+  InvalidLambdaParameters { parameters: Expr, arrow: Token<'src> },
+  #[error("Expected lambda body after '=>'")]
+  LambdaMissingBody { parameters: Vec<Identifier>, arrow: Token<'src> },
+  #[error("Expected ')' after arguments")]
+  CallNotClosed { left_paren: Token<'src>, callee: Expr, arguments: Vec<Expr> },
+  #[error("Expected property name after '.'")]
+  GetMissingName { dot: Token<'src>, object: Expr },
+  #[error("Expected ']' after list elements")]
+  ListNotClosed { left_bracket: Token<'src>, elements: Vec<Expr> },
+  #[error("Expected '}}' after map entries")]
+  MapNotClosed { left_brace: Token<'src>, entries: Vec<(Expr, Expr)> },
+  #[error("Expected ':' after map key")]
+  MapEntryMissingColon { left_brace: Token<'src>, key: Expr },
+  #[error("Expected expression after ':'")]
+  MapEntryMissingValue { left_brace: Token<'src>, key: Expr, colon: Token<'src> },
+  #[error("Expected index expression after '['")]
+  IndexMissingExpression { left_bracket: Token<'src>, object: Expr },
+  #[error("Expected ']' after index")]
+  IndexNotClosed { left_bracket: Token<'src>, object: Expr },
+  #[error("Expected expression after assignment operator")]
+  AssignmentMissingValue { target: Expr, operator: Token<'src> },
+  #[error("Invalid assignment target")]
+  InvalidAssignmentTarget { target: Expr, operator: Token<'src> },
+  #[error("Expected expression after increment or decrement operator")]
+  UpdateMissingTarget { operator: Token<'src> },
+  #[error("Invalid increment or decrement target")]
+  InvalidUpdateTarget { target: Expr, operator: Token<'src> },
+  #[error("Expected expression after range operator")]
+  RangeMissingEnd { start: Expr, operator: Token<'src> },
+  #[error("Range operators can't be chained")]
+  ChainedRange { range: Expr, operator: Token<'src> },
+  #[error("Expected expression after '**'")]
+  PowerMissingRight { left: Expr, operator: Token<'src> },
+  #[error("Expected expression after shift operator")]
+  ShiftMissingRight { left: Expr, operator: Token<'src> },
+  #[error("Expected expression after '&'")]
+  BitAndMissingRight { left: Expr, operator: Token<'src> },
+  #[error("Expected expression after '|'")]
+  BitOrMissingRight { left: Expr, operator: Token<'src> },
+  #[error("Expected expression after '^'")]
+  BitXorMissingRight { left: Expr, operator: Token<'src> },
+  #[error("Expected expression after '??'")]
+  NullishMissingRight { left: Expr, operator: Token<'src> },
 }
@@ -25,3 +25,19 @@ where
     Some(Ok(expr))
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use crate::parser::tests::parse;
+
+  #[rstest(src, expected)]
+  #[case::multiply("a * b", "(* a b)")]
+  #[case::divide("a / b", "(/ a b)")]
+  #[case::modulo("a % b", "(% a b)")]
+  #[case::left_associative("a % b * c", "(* (% a b) c)")]
+  fn parser_factor_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+}
@@ -0,0 +1,129 @@
+use crate::{
+  expr::{Expr, Identifier},
+  token::{Token, TokenKind},
+};
+
+use super::Parser;
+
+impl<'src, I> Parser<'src, I>
+where
+  I: Iterator<Item = Token<'src>>,
+{
+  /// parses the body of an arrow lambda `(a, b) => a + b`, after its `=>`.
+  ///
+  /// the parenthesized parameters were already parsed as a grouping, `parameters` must be a comma
+  /// separated list of variables to be reinterpreted as parameter names.
+  pub(super) fn arrow_lambda(
+    &mut self,
+    parameters: Expr,
+    arrow: Token<'src>,
+  ) -> Option<super::Result<'src>> {
+    if !is_parameter_list(&parameters) {
+      return Some(Err(super::Error::InvalidLambdaParameters { parameters, arrow }.into()));
+    }
+
+    self.lambda(into_parameters(parameters), arrow)
+  }
+
+  /// parses the body of a lambda taking `parameters`, after its `=>`.
+  ///
+  /// the body is a single expression that doesn't include the comma operator.
+  pub(super) fn lambda(
+    &mut self,
+    parameters: Vec<Identifier>,
+    arrow: Token<'src>,
+  ) -> Option<super::Result<'src>> {
+    match self.assignment() {
+      Some(Ok(body)) => Some(Ok(Expr::lambda(parameters, body))),
+      None => Some(Err(super::Error::LambdaMissingBody { parameters, arrow }.into())),
+      err => err,
+    }
+  }
+}
+
+/// whether `expr` is a comma separated list of variables.
+fn is_parameter_list(mut expr: &Expr) -> bool {
+  loop {
+    match expr {
+      Expr::Variable(_) => return true,
+      Expr::Binary { left, operator, right }
+        if **operator == TokenKind::Comma && matches!(**right, Expr::Variable(_)) =>
+      {
+        expr = left;
+      }
+      _ => return false,
+    }
+  }
+}
+
+/// collects the names of a parameter list checked by [`is_parameter_list`], in order.
+fn into_parameters(mut expr: Expr) -> Vec<Identifier> {
+  let mut names = Vec::new();
+
+  // comma has left-to-right associativity, so names are collected from last to first.
+  loop {
+    match &mut expr {
+      Expr::Variable(name) => {
+        names.push(std::mem::take(name));
+        break;
+      }
+      Expr::Binary { left, right, .. } => {
+        let Expr::Variable(name) = &mut **right else {
+          unreachable!("parameter lists only contain variables and commas");
+        };
+        names.push(std::mem::take(name));
+        expr = left.take();
+      }
+      _ => unreachable!("parameter lists only contain variables and commas"),
+    }
+  }
+
+  names.reverse();
+  names
+}
+
+#[cfg(test)]
+mod tests {
+  use claims::assert_matches;
+  use rstest::rstest;
+
+  use crate::parser::{
+    Error,
+    tests::{parse, parse_err},
+  };
+
+  #[rstest(src, expected)]
+  #[case::no_parameters("() => 1", "(lambda () 1)")]
+  #[case::single_parameter("(a) => a", "(lambda (a) a)")]
+  #[case::parameters("(a, b) => a + b", "(lambda (a b) (+ a b))")]
+  #[case::many_parameters("(a, b, c, d) => d", "(lambda (a b c d) d)")]
+  #[case::ternary_body("(a) => a ? 1 : 2", "(lambda (a) (? a 1 2))")]
+  #[case::lambda_body("(a) => (b) => a + b", "(lambda (a) (lambda (b) (+ a b)))")]
+  #[case::body_stops_at_comma("(a) => a, 1", "(, (lambda (a) a) 1)")]
+  #[case::list_of_lambdas("[(a) => a, () => 2]", "(list (lambda (a) a) (lambda () 2))")]
+  #[case::grouping("(a, b)", "(group (, a b))")]
+  fn parser_lambda_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+
+  #[rstest(src)]
+  #[case::literal("(1) => 1")]
+  #[case::expression("(a + b) => 1")]
+  #[case::nested_grouping("((a), b) => 1")]
+  #[case::trailing_literal("(a, 1) => 1")]
+  fn parser_lambda_invalid_parameters_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::InvalidLambdaParameters { .. });
+  }
+
+  #[rstest(src)]
+  #[case::no_parameters("() =>")]
+  #[case::parameters("(a, b) =>")]
+  fn parser_lambda_missing_body_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::LambdaMissingBody { .. });
+  }
+
+  #[test]
+  fn parser_empty_grouping_err() {
+    assert_matches!(*parse_err("() + 1"), Error::GroupingMissingExpression { .. });
+  }
+}
@@ -1,9 +1,13 @@
 /// return Expr in <code>Option<Result<Expr>></code> or else return.
+///
+/// this matches on the result directly rather than using `?`, which keeps fewer copies of the
+/// result on the stack of every grammar level in an unoptimized build.
 macro_rules! expr_or_return {
   ($e:expr) => {
-    match $e? {
-      Ok(expr) => expr,
-      Err(e) => return Some(Err(e)),
+    match $e {
+      Some(Ok(expr)) => expr,
+      Some(Err(e)) => return Some(Err(e)),
+      None => return None,
     }
   };
 }
@@ -1,24 +1,74 @@
+//! recursive descent parser for lox expressions.
+//!
+//! each precedence level has its own module, from the lowest precedence to the highest:
+//!
+//! | level      | operators                                            | associativity |
+//! |------------|------------------------------------------------------|---------------|
+//! | comma      | `,`                                                  | left          |
+//! | assignment | `=` `+=` `-=` `*=` `/=` `%=`                         | right         |
+//! | ternary    | `?:`                                                 | right         |
+//! | nullish    | `??`                                                 | left          |
+//! | range      | `..` `..=`                                           | none          |
+//! | equality   | `==` `!=`                                            | left          |
+//! | comparison | `<` `<=` `>` `>=`                                    | left          |
+//! | bit_or     | `\|`                                                 | left          |
+//! | bit_xor    | `^`                                                  | left          |
+//! | bit_and    | `&`                                                  | left          |
+//! | shift      | `<<` `>>`                                            | left          |
+//! | term       | `+` `-`                                              | left          |
+//! | factor     | `*` `/` `%`                                          | left          |
+//! | unary      | `!` `-` `~`                                          | right         |
+//! | power      | `**`                                                 | right         |
+//! | update     | prefix `++` `--`                                     | right         |
+//! | call       | `()` `[]` `.` `?.` postfix `++` `--`                 | left          |
+//! | primary    | literals, variables, groupings, lists, maps, lambdas | -             |
+
+mod assignment;
+mod bit_and;
+mod bit_or;
+mod bit_xor;
+mod call;
 mod comma;
 mod comparison;
+mod equality;
 mod error;
 mod expression;
 mod factor;
+mod lambda;
 mod macros;
+mod nullish;
+mod power;
 mod primary;
+mod range;
+mod shift;
 mod term;
 mod ternary;
 mod unary;
+mod update;
 
 use std::iter::Peekable;
 
 use self::error::{Error, Result};
 use crate::token::Token;
 
+/// maximum number of nested sub-expressions (groupings, lists, maps, calls, subscripts and ternary
+/// then-branches) the parser descends into before giving up, so that pathological input can't
+/// overflow the stack. right-associative chains like `a = b = c` or `!!a` are parsed in a loop and
+/// don't nest.
+///
+/// C requires at least 63 levels of nested parentheses. a nested sub-expression goes through every
+/// level of the grammar again, which costs about 24 KiB of stack per nesting level in an
+/// unoptimized build, so the deepest expression stays around 1.5 MiB, within the 2 MiB stack a
+/// spawned thread gets by default.
+pub const MAX_NESTING_DEPTH: usize = 64;
+
 pub struct Parser<'src, I>
 where
   I: Iterator<Item = Token<'src>>,
 {
   tokens: Peekable<I>,
+  /// number of nested sub-expressions currently being parsed.
+  depth: usize,
 }
 
 impl<'src, I> Parser<'src, I>
@@ -26,7 +76,26 @@ where
   I: Iterator<Item = Token<'src>>,
 {
   pub fn new(tokens: I) -> Self {
-    Self { tokens: tokens.peekable() }
+    Self { tokens: tokens.peekable(), depth: 0 }
+  }
+
+  /// runs `parse` one nesting level deeper, on the sub-expression opened by `token`.
+  ///
+  /// `token` is handed back to `parse`, or reported as [`Error::NestingTooDeep`] when the
+  /// sub-expression would be nested deeper than [`MAX_NESTING_DEPTH`].
+  fn nested<T>(
+    &mut self,
+    token: Token<'src>,
+    parse: impl FnOnce(&mut Self, Token<'src>) -> Option<Result<'src, T>>,
+  ) -> Option<Result<'src, T>> {
+    if self.depth >= MAX_NESTING_DEPTH {
+      return Some(Err(Error::NestingTooDeep { token }.into()));
+    }
+
+    self.depth += 1;
+    let result = parse(self, token);
+    self.depth -= 1;
+    result
   }
 }
 
@@ -43,11 +112,13 @@ where
 
 #[cfg(test)]
 mod tests {
-  use claims::{assert_matches, assert_ok, assert_some};
+  use claims::{assert_err, assert_matches, assert_none, assert_ok, assert_some};
+  use rstest::rstest;
 
   use super::*;
   use crate::expr::{AstFormatter, Expr, ExprVisitor};
   use crate::scanner::Scanner;
+  use crate::token::TokenKind;
   use std::time::Instant;
 
   #[test]
@@ -74,4 +145,187 @@ mod tests {
     assert_matches!(expr, Expr::Grouping(_));
     println!("{}", AstFormatter.visit(&expr));
   }
+
+  /// parses `src` as a single expression, formatted with [`AstFormatter`].
+  pub(super) fn parse(src: &str) -> String {
+    let tokens = Scanner::new(src).map(|t| t.unwrap());
+    let mut parser = Parser::new(tokens);
+    let expr = assert_some!(parser.next());
+    let expr = assert_ok!(expr);
+    assert_none!(parser.tokens.next());
+    AstFormatter.visit(&expr)
+  }
+
+  /// parses `src` as a single expression that fails, returning the error.
+  pub(super) fn parse_err(src: &str) -> Box<Error<'_>> {
+    let tokens = Scanner::new(src).map(|t| t.unwrap());
+    let mut parser = Parser::new(tokens);
+    let expr = assert_some!(parser.next());
+    assert_err!(expr)
+  }
+
+  /// how each grammar level groups against the other levels, from the lowest precedence to the
+  /// highest. operators within a single level are checked in the module of that level.
+  #[rstest(src, expected)]
+  // comma
+  #[case::comma_of_assignments("a = 1, b = 2", "(, (= a 1) (= b 2))")]
+  #[case::assignment_in_comma("a, b = c, d", "(, (, a (= b c)) d)")]
+  #[case::comma_before_ternary("a, b ? c : d", "(, a (? b c d))")]
+  #[case::comma_after_ternary("a ? b : c, d", "(, (? a b c) d)")]
+  #[case::comma_of_ternaries("a ? b : c, d ? e : f", "(, (? a b c) (? d e f))")]
+  #[case::comma_of_unary("-a, !b", "(, (- a) (! b))")]
+  // assignment
+  #[case::assignment_of_ternary("x = a ? b : c", "(= x (? a b c))")]
+  #[case::assignment_of_ternary_then_comma("x = a ? b : c, d", "(, (= x (? a b c)) d)")]
+  #[case::assignment_of_ternary_comma_in_then("x = a ? b, c : d", "(= x (? a (, b c) d))")]
+  #[case::chained_assignment_of_ternary("x = y = a ? b : c", "(= x (= y (? a b c)))")]
+  #[case::compound_assignment_of_ternary("x += a ? 1 : 2", "(+= x (? a 1 2))")]
+  // ternary
+  #[case::ternary_of_nullish("a ?? b ? c : d ?? e", "(? (?? a b) c (?? d e))")]
+  #[case::nullish_in_then("a ? b ?? c : d", "(? a (?? b c) d)")]
+  #[case::nullish_in_else("a ? b : c ?? d", "(? a b (?? c d))")]
+  #[case::comma_in_then("a ? 1, 2 : 3", "(? a (, 1 2) 3)")]
+  #[case::comma_in_then_and_after("a ? b, c : d, e", "(, (? a (, b c) d) e)")]
+  #[case::comma_in_nested_then("a ? b ? c, d : e : f", "(? a (? b (, c d) e) f)")]
+  #[case::grouped_comma_in_then("a ? (b, c) : d", "(? a (group (, b c)) d)")]
+  #[case::grouped_comma_in_else("a ? b : (c, d)", "(? a b (group (, c d)))")]
+  #[case::grouped_comma_condition("(a, b) ? c : d", "(? (group (, a b)) c d)")]
+  #[case::assignment_in_then("a ? b = 1 : c", "(? a (= b 1) c)")]
+  #[case::assignments_in_then("a ? b = 1, c = 2 : d", "(? a (, (= b 1) (= c 2)) d)")]
+  #[case::range_condition("1..2 ? a : b", "(? (.. 1 2) a b)")]
+  #[case::range_branches("a ? 1..2 : 3..=4", "(? a (.. 1 2) (..= 3 4))")]
+  #[case::equality_condition("a == b ? c : d", "(? (== a b) c d)")]
+  #[case::inequality_condition("a != b ? !c : ~d", "(? (!= a b) (! c) (~ d))")]
+  #[case::comparison_condition("a < b ? c : d", "(? (< a b) c d)")]
+  #[case::comparison_branches("a ? b < c : d > e", "(? a (< b c) (> d e))")]
+  #[case::arithmetic_ternary("1 + 2 ? 3 * 4 : 5 - 6", "(? (+ 1 2) (* 3 4) (- 5 6))")]
+  #[case::unary_condition("!a ? b : c", "(? (! a) b c)")]
+  #[case::unary_branches("a ? -b : -c", "(? a (- b) (- c))")]
+  #[case::prefix_update_condition("++x ? a : b", "(? (pre++ x) a b)")]
+  #[case::postfix_update_branches("a ? x++ : x--", "(? a (post++ x) (post-- x))")]
+  #[case::call_condition("f(a, b) ? c : d", "(? (call f a b) c d)")]
+  #[case::subscripts_in_branches("a ? xs[0] : obj.f", "(? a (index xs 0) (. obj f))")]
+  #[case::ternary_argument("f(a ? b : c, d)", "(call f (? a b c) d)")]
+  #[case::lambda_in_then("a ? (b) => b : c", "(? a (lambda (b) b) c)")]
+  // nullish
+  #[case::nullish_of_range("a..b ?? c", "(?? (.. a b) c)")]
+  #[case::nullish_of_comparison("a ?? b < c", "(?? a (< b c))")]
+  #[case::nullish_of_optional_chain("a?.b ?? c", "(?? (chain (?. a b)) c)")]
+  // range
+  #[case::range_of_equality("a == b..c != d", "(.. (== a b) (!= c d))")]
+  #[case::range_of_comparison("a < b..c", "(.. (< a b) c)")]
+  #[case::range_of_terms("a + 1..b * 2", "(.. (+ a 1) (* b 2))")]
+  // equality
+  #[case::equality_of_comparison("a < b == c < d", "(== (< a b) (< c d))")]
+  #[case::equality_of_unary("!a == b", "(== (! a) b)")]
+  // comparison
+  #[case::comparison_of_bit_or("a | b >= c | d", "(>= (| a b) (| c d))")]
+  #[case::comparison_of_terms("a + b < c + d", "(< (+ a b) (+ c d))")]
+  #[case::comparison_of_unary("-a < b", "(< (- a) b)")]
+  // bitwise or
+  #[case::bit_or_of_bit_xor("a ^ b | c ^ d", "(| (^ a b) (^ c d))")]
+  #[case::bit_or_of_bit_and_and_bit_xor("a & b | c ^ d", "(| (& a b) (^ c d))")]
+  // bitwise xor
+  #[case::bit_xor_of_bit_and("a & b ^ c & d", "(^ (& a b) (& c d))")]
+  // bitwise and
+  #[case::bit_and_of_shift("a << b & c >> d", "(& (<< a b) (>> c d))")]
+  #[case::bit_and_of_unary("~a & b", "(& (~ a) b)")]
+  // shift
+  #[case::shift_of_term("a + b << c - d", "(<< (+ a b) (- c d))")]
+  // term
+  #[case::term_of_factor("a * b - c / d", "(- (* a b) (/ c d))")]
+  // factor
+  #[case::factor_of_unary("-a * !b", "(* (- a) (! b))")]
+  #[case::factor_of_power("a % b ** c", "(% a (** b c))")]
+  // unary
+  #[case::unary_of_power("-a ** -b", "(- (** a (- b)))")]
+  #[case::bit_not_of_power("~a ** b", "(~ (** a b))")]
+  #[case::unary_of_update("-++x", "(- (pre++ x))")]
+  #[case::unary_of_call("-f(x)", "(- (call f x))")]
+  #[case::unary_of_optional_chain("!obj?.ok", "(! (chain (?. obj ok)))")]
+  // power
+  #[case::power_of_update("++a ** b--", "(** (pre++ a) (post-- b))")]
+  #[case::power_of_subscript("xs[0] ** 2", "(** (index xs 0) 2)")]
+  // update
+  #[case::update_of_subscripts("a.b[0]++", "(post++ (index (. a b) 0))")]
+  // call
+  #[case::call_of_grouping("(a + b)(c)", "(call (group (+ a b)) c)")]
+  #[case::call_of_lambda("((a) => a)(1)", "(call (group (lambda (a) a)) 1)")]
+  #[case::call_of_list("[1, 2][0]", "(index (list 1 2) 0)")]
+  #[case::call_of_optional_chain("a.b?.c[d](e)", "(chain (call (index (?. (. a b) c) d) e))")]
+  // primary
+  #[case::list_of_ternary("[a, b ? c : d]", "(list a (? b c d))")]
+  fn parser_grammar_conformance_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+
+  #[test]
+  fn parser_assignment_to_ternary_err() {
+    assert_matches!(*parse_err("a ? b : c = d"), Error::InvalidAssignmentTarget { .. });
+  }
+
+  /// repeats `open` and `close` around `1`, `depth` times.
+  fn nest(open: &str, close: &str, depth: usize) -> String {
+    format!("{}1{}", open.repeat(depth), close.repeat(depth))
+  }
+
+  // these run on the default 2 MiB stack of test threads, so that an unoptimized build catches a
+  // grammar level that makes nesting up to the limit overflow a freshly spawned thread.
+  #[rstest(open, close)]
+  #[case::grouping("(", ")")]
+  #[case::list("[", "]")]
+  #[case::map("{1: ", "}")]
+  #[case::call("f(", ")")]
+  #[case::index("xs[", "]")]
+  #[case::lambda("() => ", "")]
+  #[case::ternary_then("a ? ", " : 2")]
+  fn parser_nesting_at_limit_ok(open: &str, close: &str) {
+    parse(&nest(open, close, MAX_NESTING_DEPTH));
+  }
+
+  #[rstest(open, close, kind)]
+  #[case::grouping("(", ")", TokenKind::LeftParen)]
+  #[case::list("[", "]", TokenKind::LeftBracket)]
+  #[case::map("{1: ", "}", TokenKind::LeftBrace)]
+  #[case::call("f(", ")", TokenKind::LeftParen)]
+  #[case::index("xs[", "]", TokenKind::LeftBracket)]
+  #[case::lambda("() => ", "", TokenKind::LeftParen)]
+  #[case::ternary_then("true ? ", " : 2", TokenKind::Question)]
+  fn parser_nesting_too_deep_err(open: &str, close: &str, kind: TokenKind) {
+    let src = nest(open, close, 100_000);
+    assert_matches!(
+      *parse_err(&src),
+      Error::NestingTooDeep { token: Token { kind: ref token_kind, .. } } if *token_kind == kind
+    );
+  }
+
+  /// right-associative chains are folded in a loop, they don't count toward the nesting limit.
+  #[rstest(open)]
+  #[case::ternary_else("a ? 1 : ")]
+  #[case::assignment("a = ")]
+  #[case::compound_assignment("a += ")]
+  #[case::unary("!")]
+  #[case::negation("- ")]
+  #[case::power("2 ** ")]
+  #[case::power_of_negation("2 ** -")]
+  fn parser_right_associative_chain_ok(open: &str) {
+    parse(&nest(open, "", MAX_NESTING_DEPTH * 4));
+  }
+
+  /// chains parsed in a loop build trees as deep as they are long, which must drop without
+  /// overflowing the stack. they aren't formatted, as formatting recurses.
+  #[rstest(src)]
+  #[case::sum(format!("1{}", " + 1".repeat(100_000)))]
+  #[case::calls(format!("f{}", "()".repeat(100_000)))]
+  #[case::properties(format!("a{}", ".b".repeat(100_000)))]
+  #[case::ternary_else(format!("{}1", "a ? 1 : ".repeat(100_000)))]
+  #[case::assignment(format!("{}1", "a = ".repeat(100_000)))]
+  #[case::unary(format!("{}a", "!".repeat(100_000)))]
+  #[case::power(format!("{}2", "2 ** ".repeat(100_000)))]
+  fn parser_long_chain_drop_ok(src: String) {
+    let tokens = Scanner::new(&src).map(|t| t.unwrap());
+    let mut parser = Parser::new(tokens);
+    let expr = assert_some!(parser.next());
+    drop(assert_ok!(expr));
+  }
 }
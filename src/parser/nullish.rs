@@ -0,0 +1,57 @@
+use super::macros::expr_or_return;
+use crate::expr::{Expr, NullishOperator, Terminal};
+use crate::parser::Parser;
+use crate::token::Token;
+
+impl<'src, I> Parser<'src, I>
+where
+  I: Iterator<Item = Token<'src>>,
+{
+  /// parse a nullish coalescing expression `a ?? b`, `b` is only evaluated when `a` is nil.
+  ///
+  /// nullish coalescing has left-to-right associativity.
+  pub(super) fn nullish(&mut self) -> Option<super::Result<'src>> {
+    let mut expr = expr_or_return!(self.range());
+
+    while let Some(operator) = self.tokens.next_if(|t| NullishOperator::matches(t)) {
+      let right = match self.range() {
+        Some(Ok(right)) => right,
+        None => {
+          return Some(Err(super::Error::NullishMissingRight { left: expr, operator }.into()));
+        }
+        err => return err,
+      };
+      let operator: NullishOperator =
+        operator.try_into().expect("Nullish operators are binary operators");
+      expr = Expr::binary(expr, operator.into(), right);
+    }
+
+    Some(Ok(expr))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use claims::assert_matches;
+  use rstest::rstest;
+
+  use crate::parser::{
+    Error,
+    tests::{parse, parse_err},
+  };
+
+  #[rstest(src, expected)]
+  #[case::single("a ?? b", "(?? a b)")]
+  #[case::left_associative("a ?? b ?? c", "(?? (?? a b) c)")]
+  fn parser_nullish_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+
+  #[rstest(src)]
+  #[case::end("a ??")]
+  #[case::chained("a ?? b ??")]
+  #[case::before_closing_paren("(a ??)")]
+  fn parser_nullish_missing_right_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::NullishMissingRight { .. });
+  }
+}
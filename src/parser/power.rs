@@ -0,0 +1,72 @@
+use super::macros::expr_or_return;
+use super::unary::prefixed;
+use crate::expr::{Expr, PowerOperator, Terminal};
+use crate::parser::Parser;
+use crate::token::Token;
+
+impl<'src, I> Parser<'src, I>
+where
+  I: Iterator<Item = Token<'src>>,
+{
+  /// parse a power expression.
+  ///
+  /// power has right-to-left associativity and binds tighter than a unary operator on its left,
+  /// so `-2 ** 2` is `-(2 ** 2)`, while the exponent may itself be a unary expression, `2 ** -1`.
+  pub(super) fn power(&mut self) -> Option<super::Result<'src>> {
+    let mut expr = expr_or_return!(self.update());
+
+    // the bases are collected in a loop and folded from the right, so that a long chain of
+    // `a ** b ** …` doesn't nest. the unary operators in front of each exponent are kept with it.
+    let mut bases = Vec::new();
+    while let Some(operator) = self.tokens.next_if(|t| PowerOperator::matches(t)) {
+      let operators = self.unary_operators();
+      let right = match self.update() {
+        Some(Ok(right)) => right,
+        None => {
+          return Some(Err(super::Error::PowerMissingRight { left: expr, operator }.into()));
+        }
+        err => return err,
+      };
+      let operator: PowerOperator =
+        operator.try_into().expect("Power operators are binary operators");
+
+      bases.push((expr, operator, operators));
+      expr = right;
+    }
+
+    let expr = bases.into_iter().rev().fold(expr, |right, (left, operator, operators)| {
+      Expr::binary(left, operator.into(), prefixed(operators, right))
+    });
+
+    Some(Ok(expr))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use claims::assert_matches;
+  use rstest::rstest;
+
+  use crate::parser::{
+    Error,
+    tests::{parse, parse_err},
+  };
+
+  #[rstest(src, expected)]
+  #[case::single("2 ** 3", "(** 2 3)")]
+  #[case::right_associative("2 ** 3 ** 2", "(** 2 (** 3 2))")]
+  #[case::negative_exponent("2 ** -1", "(** 2 (- 1))")]
+  #[case::negative_exponent_power("2 ** -3 ** 2", "(** 2 (- (** 3 2)))")]
+  fn parser_power_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+
+  #[rstest(src)]
+  #[case::end("2 **")]
+  #[case::chained("2 ** 3 **")]
+  #[case::after_unary("2 ** -")]
+  #[case::before_closing_paren("(2 **)")]
+  fn parser_power_missing_right_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::PowerMissingRight { .. });
+  }
+}
@@ -1,4 +1,4 @@
-use crate::expr::{Expr, Literal, Terminal};
+use crate::expr::{Expr, Identifier, Literal, Terminal};
 use crate::parser::Parser;
 use crate::token::{Token, TokenKind};
 
@@ -13,19 +13,190 @@ where
       return Some(Ok(literal));
     }
 
+    if let Some(variable) =
+      self.tokens.next_if(|t| Identifier::matches(t)).map(|t| Expr::variable(t.try_into().unwrap()))
+    {
+      return Some(Ok(variable));
+    }
+
+    if let Some(left_bracket) = self.tokens.next_if(|t| t.kind == TokenKind::LeftBracket) {
+      return self.nested(left_bracket, Self::list);
+    }
+
+    if let Some(left_brace) = self.tokens.next_if(|t| t.kind == TokenKind::LeftBrace) {
+      return self.nested(left_brace, Self::map);
+    }
+
     if let Some(right_paren) = self.tokens.next_if(|t| t.kind == TokenKind::LeftParen) {
-      let expr = match self.expression() {
-        Some(Ok(expr)) => expr,
-        None => return Some(Err(super::Error::GroupingMissingExpression { right_paren })),
+      return self.nested(right_paren, Self::grouping);
+    }
+
+    None
+  }
+
+  /// parses the rest of a grouping after its opening `(`, or of an arrow lambda when the closing
+  /// `)` is followed by `=>`.
+  fn grouping(&mut self, right_paren: Token<'src>) -> Option<super::Result<'src>> {
+    if self.tokens.next_if(|t| t.kind == TokenKind::RightParen).is_some() {
+      return match self.tokens.next_if(|t| t.kind == TokenKind::EqualGreater) {
+        Some(arrow) => self.lambda(Vec::new(), arrow),
+        None => Some(Err(super::Error::GroupingMissingExpression { right_paren }.into())),
+      };
+    }
+
+    let expr = match self.expression() {
+      Some(Ok(expr)) => expr,
+      None => return Some(Err(super::Error::GroupingMissingExpression { right_paren }.into())),
+      err => return err,
+    };
+
+    if self.tokens.next_if(|t| t.kind == TokenKind::RightParen).is_none() {
+      return Some(Err(super::Error::GroupingNotClosed { right_paren, expr }.into()));
+    }
+
+    match self.tokens.next_if(|t| t.kind == TokenKind::EqualGreater) {
+      Some(arrow) => self.arrow_lambda(expr, arrow),
+      None => Some(Ok(Expr::grouping(expr))),
+    }
+  }
+
+  /// parses the rest of a list literal, after its opening `[`.
+  ///
+  /// elements are separated by commas, a trailing comma is allowed.
+  fn list(&mut self, left_bracket: Token<'src>) -> Option<super::Result<'src>> {
+    let mut elements = Vec::new();
+
+    loop {
+      if self.tokens.next_if(|t| t.kind == TokenKind::RightBracket).is_some() {
+        return Some(Ok(Expr::list(elements)));
+      }
+
+      match self.assignment() {
+        Some(Ok(element)) => elements.push(element),
+        None => return Some(Err(super::Error::ListNotClosed { left_bracket, elements }.into())),
+        err => return err,
+      }
+
+      if self.tokens.next_if(|t| t.kind == TokenKind::Comma).is_none() {
+        return match self.tokens.next_if(|t| t.kind == TokenKind::RightBracket) {
+          Some(_) => Some(Ok(Expr::list(elements))),
+          None => Some(Err(super::Error::ListNotClosed { left_bracket, elements }.into())),
+        };
+      }
+    }
+  }
+
+  /// parses the rest of a map literal, after its opening `{`.
+  ///
+  /// entries are `key: value` pairs separated by commas, a trailing comma is allowed. entries are
+  /// kept in source order.
+  ///
+  /// a `{` reaching `primary()` is in expression position, so it always starts a map literal:
+  /// blocks are statements and never get here.
+  fn map(&mut self, left_brace: Token<'src>) -> Option<super::Result<'src>> {
+    let mut entries = Vec::new();
+
+    loop {
+      if self.tokens.next_if(|t| t.kind == TokenKind::RightBrace).is_some() {
+        return Some(Ok(Expr::map(entries)));
+      }
+
+      let key = match self.assignment() {
+        Some(Ok(key)) => key,
+        None => return Some(Err(super::Error::MapNotClosed { left_brace, entries }.into())),
         err => return err,
       };
 
-      return match self.tokens.next_if(|t| t.kind == TokenKind::RightParen) {
-        Some(_) => Some(Ok(Expr::grouping(expr))),
-        None => Some(Err(super::Error::GroupingNotClosed { right_paren, expr })),
+      let Some(colon) = self.tokens.next_if(|t| t.kind == TokenKind::Colon) else {
+        return Some(Err(super::Error::MapEntryMissingColon { left_brace, key }.into()));
       };
+
+      match self.assignment() {
+        Some(Ok(value)) => entries.push((key, value)),
+        None => {
+          return Some(Err(super::Error::MapEntryMissingValue { left_brace, key, colon }.into()));
+        }
+        err => return err,
+      }
+
+      if self.tokens.next_if(|t| t.kind == TokenKind::Comma).is_none() {
+        return match self.tokens.next_if(|t| t.kind == TokenKind::RightBrace) {
+          Some(_) => Some(Ok(Expr::map(entries))),
+          None => Some(Err(super::Error::MapNotClosed { left_brace, entries }.into())),
+        };
+      }
     }
+  }
+}
 
-    None
+#[cfg(test)]
+mod tests {
+  use claims::assert_matches;
+  use rstest::rstest;
+
+  use crate::parser::{
+    Error,
+    tests::{parse, parse_err},
+  };
+
+  #[rstest(src, expected)]
+  #[case::number("1", "1")]
+  #[case::string("\"a\"", "\"a\"")]
+  #[case::nil("nil", "nil")]
+  #[case::variable("a", "a")]
+  #[case::grouping("(a, b)", "(group (, a b))")]
+  fn parser_primary_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+
+  #[rstest(src, expected)]
+  #[case::empty("[]", "(list)")]
+  #[case::single("[1]", "(list 1)")]
+  #[case::multiple("[1, \"two\", nil]", "(list 1 \"two\" nil)")]
+  #[case::trailing_comma("[1, 2,]", "(list 1 2)")]
+  #[case::nested("[[1], []]", "(list (list 1) (list))")]
+  #[case::expressions("[1 + 2, a ? b : c]", "(list (+ 1 2) (? a b c))")]
+  #[case::grouped_comma("[(1, 2)]", "(list (group (, 1 2)))")]
+  fn parser_list_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+
+  #[rstest(src)]
+  #[case::unclosed("[1, 2")]
+  #[case::missing_comma("[1 2]")]
+  #[case::missing_element("[1, , 2]")]
+  fn parser_list_not_closed_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::ListNotClosed { .. });
+  }
+
+  #[rstest(src, expected)]
+  #[case::empty("{}", "(map)")]
+  #[case::string_key("{\"a\": 1}", "(map (\"a\" 1))")]
+  #[case::mixed_keys("{\"a\": 1, 2: \"b\"}", "(map (\"a\" 1) (2 \"b\"))")]
+  #[case::insertion_order("{3: 1, 1: 2, 2: 3}", "(map (3 1) (1 2) (2 3))")]
+  #[case::trailing_comma("{1: 2,}", "(map (1 2))")]
+  #[case::nested("{1: {2: [3]}}", "(map (1 (map (2 (list 3)))))")]
+  #[case::ternary_key("{a ? b : c: d}", "(map ((? a b c) d))")]
+  #[case::ternary_value("{a: b ? c : d}", "(map (a (? b c d)))")]
+  fn parser_map_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+
+  #[rstest(src)]
+  #[case::unclosed("{1: 2")]
+  #[case::missing_comma("{1: 2 3: 4}")]
+  #[case::missing_entry("{1: 2, , 3: 4}")]
+  fn parser_map_not_closed_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::MapNotClosed { .. });
+  }
+
+  #[test]
+  fn parser_map_entry_missing_colon_err() {
+    assert_matches!(*parse_err("{1 2}"), Error::MapEntryMissingColon { .. });
+  }
+
+  #[test]
+  fn parser_map_entry_missing_value_err() {
+    assert_matches!(*parse_err("{1: }"), Error::MapEntryMissingValue { .. });
   }
 }
@@ -0,0 +1,70 @@
+use super::macros::expr_or_return;
+use crate::expr::{Expr, RangeOperator, Terminal};
+use crate::parser::Parser;
+use crate::token::Token;
+
+impl<'src, I> Parser<'src, I>
+where
+  I: Iterator<Item = Token<'src>>,
+{
+  /// parses a range expression, either exclusive `start..end` or inclusive `start..=end`.
+  ///
+  /// range is non-associative, `a..b..c` is an error rather than `(a..b)..c` or `a..(b..c)`.
+  pub(super) fn range(&mut self) -> Option<super::Result<'src>> {
+    let start = expr_or_return!(self.equality());
+
+    let Some(operator) = self.tokens.next_if(|t| RangeOperator::matches(t)) else {
+      return Some(Ok(start));
+    };
+
+    let end = match self.equality() {
+      Some(Ok(end)) => end,
+      None => return Some(Err(super::Error::RangeMissingEnd { start, operator }.into())),
+      err => return err,
+    };
+
+    let operator: RangeOperator =
+      operator.try_into().expect("range operators are binary operators");
+    let range = Expr::binary(start, operator.into(), end);
+
+    match self.tokens.next_if(|t| RangeOperator::matches(t)) {
+      Some(operator) => Some(Err(super::Error::ChainedRange { range, operator }.into())),
+      None => Some(Ok(range)),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use claims::assert_matches;
+  use rstest::rstest;
+
+  use crate::parser::{
+    Error,
+    tests::{parse, parse_err},
+  };
+
+  #[rstest(src, expected)]
+  #[case::exclusive("0..10", "(.. 0 10)")]
+  #[case::inclusive("0..=10", "(..= 0 10)")]
+  #[case::decimal_bounds("0.5..1.5", "(.. 0.5 1.5)")]
+  #[case::grouped_start("(0..1)..2", "(.. (group (.. 0 1)) 2)")]
+  fn parser_range_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+
+  #[rstest(src)]
+  #[case::exclusive("0..")]
+  #[case::inclusive("0..=")]
+  fn parser_range_missing_end_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::RangeMissingEnd { .. });
+  }
+
+  #[rstest(src)]
+  #[case::exclusive("0..1..2")]
+  #[case::inclusive("0..=1..=2")]
+  #[case::mixed("0..1..=2")]
+  fn parser_range_non_associative_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::ChainedRange { .. });
+  }
+}
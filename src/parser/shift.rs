@@ -0,0 +1,58 @@
+use super::macros::expr_or_return;
+use crate::expr::{Expr, ShiftOperator, Terminal};
+use crate::parser::Parser;
+use crate::token::Token;
+
+impl<'src, I> Parser<'src, I>
+where
+  I: Iterator<Item = Token<'src>>,
+{
+  /// parse a shift expression.
+  ///
+  /// shift has left-to-right associativity.
+  pub(super) fn shift(&mut self) -> Option<super::Result<'src>> {
+    let mut expr = expr_or_return!(self.term());
+
+    while let Some(operator) = self.tokens.next_if(|t| ShiftOperator::matches(t)) {
+      let right = match self.term() {
+        Some(Ok(right)) => right,
+        None => {
+          return Some(Err(super::Error::ShiftMissingRight { left: expr, operator }.into()));
+        }
+        err => return err,
+      };
+      let operator: ShiftOperator =
+        operator.try_into().expect("Shift operators are binary operators");
+      expr = Expr::binary(expr, operator.into(), right);
+    }
+
+    Some(Ok(expr))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use claims::assert_matches;
+  use rstest::rstest;
+
+  use crate::parser::{
+    Error,
+    tests::{parse, parse_err},
+  };
+
+  #[rstest(src, expected)]
+  #[case::left("a << b", "(<< a b)")]
+  #[case::right("a >> b", "(>> a b)")]
+  #[case::left_associative("a << b >> c", "(>> (<< a b) c)")]
+  fn parser_shift_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+
+  #[rstest(src)]
+  #[case::end("a <<")]
+  #[case::chained("a << b <<")]
+  #[case::before_closing_paren("(a <<)")]
+  fn parser_shift_missing_right_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::ShiftMissingRight { .. });
+  }
+}
@@ -25,3 +25,18 @@ where
     Some(Ok(expr))
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use crate::parser::tests::parse;
+
+  #[rstest(src, expected)]
+  #[case::add("a + b", "(+ a b)")]
+  #[case::subtract("a - b", "(- a b)")]
+  #[case::left_associative("a - b + c", "(+ (- a b) c)")]
+  fn parser_term_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+}
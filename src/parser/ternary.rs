@@ -11,51 +11,89 @@ where
 {
   /// parse a ternary expression.
   ///
-  /// ternary has right-to-left associativity.
+  /// ```text
+  /// ternary → nullish ( "?" expression ":" ternary )?
+  /// ```
+  ///
+  /// this follows the conditional operator of C. ternary has right-to-left associativity,
+  /// `a ? b : c ? d : e` is `a ? b : (c ? d : e)`. the then-branch is delimited by `?` and `:` so it
+  /// is a full expression, comma included, `a ? 1, 2 : 3` is `a ? (1, 2) : 3`. the else-branch
+  /// stops before assignment and comma, so `a ? b : c, d` is `(a ? b : c), d` and `a ? b : c = d`
+  /// is an invalid assignment target rather than `a ? b : (c = d)`.
   pub(super) fn ternary(&mut self) -> Option<super::Result<'src>> {
-    let mut expr = expr_or_return!(self.comparison());
+    let mut expr = expr_or_return!(self.nullish());
 
-    if let Some(then_operator) = self.tokens.next_if(|t| TernaryThenOperator::matches(t)) {
-      let then_branch = match self.expression() {
-        Some(Ok(expr)) => expr,
-        None => {
-          return Some(Err(super::Error::TernaryMissingThenBranch {
-            question: expr,
-            then_operator,
-          }));
-        }
-        err => return err,
-      };
+    // the else-branches are collected in a loop and folded from the right, so that a long chain of
+    // `a ? b : c ? d : …` doesn't nest any deeper than its then-branches.
+    let mut arms = Vec::new();
+    while let Some(then_operator) = self.tokens.next_if(|t| TernaryThenOperator::matches(t)) {
+      let ThenBranch { question, then_operator, then_branch, else_operator } = expr_or_return!(
+        self.nested(then_operator, |parser, then_operator| parser.then_branch(expr, then_operator))
+      );
 
-      let Some(else_operator) = self.tokens.next_if(|t| TernaryElseOperator::matches(t)) else {
-        return Some(Err(super::Error::TernaryMissingElseOperator {
-          question: expr,
-          then_operator,
-          then_branch,
-        }));
-      };
-      let else_branch = match self.ternary() {
-        Some(Ok(else_branch)) => else_branch,
+      expr = match self.nullish() {
+        Some(Ok(expr)) => expr,
         None => {
-          return Some(Err(super::Error::TernaryMissingElseBranch {
-            question: expr,
-            then_operator,
-            then_branch,
-            else_operator,
-          }));
+          return Some(Err(
+            super::Error::TernaryMissingElseBranch {
+              question,
+              then_operator,
+              then_branch,
+              else_operator,
+            }
+            .into(),
+          ));
         }
         err => return err,
       };
-      expr = Expr::ternary(expr, then_branch, else_branch);
+      arms.push((question, then_branch));
     }
 
+    let expr = arms.into_iter().rev().fold(expr, |else_branch, (question, then_branch)| {
+      Expr::ternary(question, then_branch, else_branch)
+    });
+
     Some(Ok(expr))
   }
+
+  /// parses the then-branch of a ternary on `question` up to its `:`, after its `?`.
+  fn then_branch(
+    &mut self,
+    question: Expr,
+    then_operator: Token<'src>,
+  ) -> Option<super::Result<'src, ThenBranch<'src>>> {
+    let then_branch = match self.expression() {
+      Some(Ok(expr)) => expr,
+      None => {
+        return Some(Err(
+          super::Error::TernaryMissingThenBranch { question, then_operator }.into(),
+        ));
+      }
+      Some(Err(err)) => return Some(Err(err)),
+    };
+
+    let Some(else_operator) = self.tokens.next_if(|t| TernaryElseOperator::matches(t)) else {
+      return Some(Err(
+        super::Error::TernaryMissingElseOperator { question, then_operator, then_branch }.into(),
+      ));
+    };
+
+    Some(Ok(ThenBranch { question, then_operator, then_branch, else_operator }))
+  }
+}
+
+/// a ternary parsed up to its `:`, waiting for its else-branch.
+struct ThenBranch<'src> {
+  question: Expr,
+  then_operator: Token<'src>,
+  then_branch: Expr,
+  else_operator: Token<'src>,
 }
 
 #[cfg(test)]
 mod tests {
   use claims::{assert_matches, assert_ok, assert_some};
+  use rstest::rstest;
 
   use crate::{
     expr::{AstFormatter, ExprVisitor},
@@ -63,6 +101,7 @@ mod tests {
   };
 
   use super::*;
+  use crate::parser::tests::parse;
 
   #[test]
   fn parser_ternary_single_ok() {
@@ -105,4 +144,15 @@ mod tests {
     assert_matches!(expr, Expr::Ternary { condition: _, then_branch: _, else_branch: _ });
     println!("{:?}", AstFormatter.visit(&expr));
   }
+
+  #[rstest(src, expected)]
+  #[case::single("a ? b : c", "(? a b c)")]
+  #[case::right_associative("a ? b : c ? d : e", "(? a b (? c d e))")]
+  #[case::else_chain("a ? b : c ? d : e ? f : g", "(? a b (? c d (? e f g)))")]
+  #[case::in_then("a ? b ? c : d : e", "(? a (? b c d) e)")]
+  #[case::in_both("a ? b ? c : d : e ? f : g", "(? a (? b c d) (? e f g))")]
+  #[case::grouped_condition("(a ? b : c) ? d : e", "(? (group (? a b c)) d e)")]
+  fn parser_ternary_table_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
 }
@@ -9,17 +9,46 @@ where
 {
   /// parse a unary expression.
   ///
-  /// unary has left-to-right associativity.
+  /// unary has right-to-left associativity, operators can be repeated as in `!!a` or `- -1`. they
+  /// are taken in a loop and applied from the right, so that a long run of them doesn't nest.
   pub(super) fn unary(&mut self) -> Option<super::Result<'src>> {
-    let Some(operator) =
-      self.tokens.next_if(|t| UnaryOperator::matches(t)).map(|t| t.try_into().unwrap())
-    else {
-      return self.primary();
-    };
+    let operators = self.unary_operators();
+    let right = expr_or_return!(self.power());
 
-    let right = expr_or_return!(self.primary());
-    let expr = Expr::unary(operator, right);
+    Some(Ok(prefixed(operators, right)))
+  }
+
+  /// takes the unary operators in front of an operand, as many as there are.
+  pub(super) fn unary_operators(&mut self) -> Vec<UnaryOperator> {
+    let mut operators = Vec::new();
+    while let Some(token) = self.tokens.next_if(|t| UnaryOperator::matches(t)) {
+      operators.push(token.try_into().unwrap());
+    }
+
+    operators
+  }
+}
+
+/// applies the unary `operators` to `right`, the closest to it first.
+pub(super) fn prefixed(operators: Vec<UnaryOperator>, right: Expr) -> Expr {
+  operators.into_iter().rev().fold(right, |right, operator| Expr::unary(operator, right))
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use crate::parser::tests::parse;
 
-    Some(Ok(expr))
+  #[rstest(src, expected)]
+  #[case::negate("-1", "(- 1)")]
+  #[case::not("!true", "(! true)")]
+  #[case::bitwise_not("~x", "(~ x)")]
+  #[case::double_not("!!true", "(! (! true))")]
+  #[case::double_negate("- -1", "(- (- 1))")]
+  #[case::mixed("!-~a", "(! (- (~ a)))")]
+  #[case::repeated_power("- -2 ** 2", "(- (- (** 2 2)))")]
+  fn parser_unary_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
   }
 }
@@ -0,0 +1,68 @@
+use crate::{
+  expr::{Expr, Terminal, UpdateOperator},
+  token::Token,
+};
+
+use super::{Parser, assignment::is_assignable};
+
+impl<'src, I> Parser<'src, I>
+where
+  I: Iterator<Item = Token<'src>>,
+{
+  /// parses an update expression, a prefix increment `++x` or decrement `--x`.
+  ///
+  /// postfix increments and decrements are parsed along with call expressions.
+  pub(super) fn update(&mut self) -> Option<super::Result<'src>> {
+    let Some(operator) = self.tokens.next_if(|t| UpdateOperator::matches(t)) else {
+      return self.call();
+    };
+
+    let target = match self.call() {
+      Some(Ok(target)) => target,
+      None => return Some(Err(super::Error::UpdateMissingTarget { operator }.into())),
+      err => return err,
+    };
+
+    if !is_assignable(&target) {
+      return Some(Err(super::Error::InvalidUpdateTarget { target, operator }.into()));
+    }
+
+    Some(Ok(Expr::update(operator.try_into().unwrap(), target, true)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use claims::assert_matches;
+  use rstest::rstest;
+
+  use crate::parser::{
+    Error,
+    tests::{parse, parse_err},
+  };
+
+  #[rstest(src, expected)]
+  #[case::increment("++x", "(pre++ x)")]
+  #[case::decrement("--x", "(pre-- x)")]
+  #[case::increment_index("++xs[i]", "(pre++ (index xs i))")]
+  #[case::postfix("x++", "(post++ x)")]
+  #[case::increment_property("++obj.f", "(pre++ (. obj f))")]
+  #[case::decrement_nested_property("--a.b.c", "(pre-- (. (. a b) c))")]
+  fn parser_update_ok(src: &str, expected: &str) {
+    assert_eq!(expected, parse(src));
+  }
+
+  #[rstest(src)]
+  #[case::literal("++1")]
+  #[case::grouping("--(x)")]
+  #[case::slice("++xs[1:]")]
+  #[case::optional_property("++obj?.f")]
+  fn parser_prefix_update_invalid_target_err(src: &str) {
+    assert_matches!(*parse_err(src), Error::InvalidUpdateTarget { .. });
+  }
+
+  #[test]
+  fn parser_prefix_update_missing_target_err() {
+    assert_matches!(*parse_err("++"), Error::UpdateMissingTarget { .. });
+  }
+}
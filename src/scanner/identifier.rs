@@ -16,12 +16,15 @@ impl<'a> Scanner<'a> {
 
     match lexeme {
       "and" => self.just(TokenKind::And),
+      "break" => self.just(TokenKind::Break),
       "class" => self.just(TokenKind::Class),
+      "continue" => self.just(TokenKind::Continue),
       "else" => self.just(TokenKind::Else),
       "false" => self.just(TokenKind::False),
       "for" => self.just(TokenKind::For),
       "fun" => self.just(TokenKind::Fun),
       "if" => self.just(TokenKind::If),
+      "in" => self.just(TokenKind::In),
       "nil" => self.just(TokenKind::Nil),
       "or" => self.just(TokenKind::Or),
       "print" => self.just(TokenKind::Print),
@@ -35,3 +38,38 @@ impl<'a> Scanner<'a> {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use claims::{assert_matches, assert_none};
+  use rstest::rstest;
+
+  use super::*;
+
+  #[rstest(source, expected_kind)]
+  #[case::break_keyword("break", TokenKind::Break)]
+  #[case::continue_keyword("continue", TokenKind::Continue)]
+  #[case::in_keyword("in", TokenKind::In)]
+  #[case::while_keyword("while", TokenKind::While)]
+  fn lex_keyword_ok(source: &str, expected_kind: TokenKind) {
+    let mut scanner = Scanner::new(source);
+    assert_matches!(
+      scanner.next(),
+      Some(Ok(Token { kind, lexeme: None, line: 1 })) if kind == expected_kind
+    );
+    assert_none!(scanner.next());
+  }
+
+  #[rstest(source)]
+  #[case::keyword_prefix("breaker")]
+  #[case::keyword_suffix("do_continue")]
+  #[case::keyword_prefix_in("index")]
+  fn lex_identifier_ok(source: &str) {
+    let mut scanner = Scanner::new(source);
+    assert_matches!(
+      scanner.next(),
+      Some(Ok(Token { kind: TokenKind::Identifier, lexeme: Some(lexeme), line: 1 })) if lexeme == source
+    );
+    assert_none!(scanner.next());
+  }
+}
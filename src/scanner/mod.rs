@@ -50,6 +50,12 @@ impl<'a> Scanner<'a> {
     self.just(kind)
   }
 
+  /// consumes the next `length` characters, all ASCII, as a token of `kind`.
+  fn multi(&mut self, length: usize, kind: TokenKind) -> Token<'a> {
+    self.rest = &self.rest[length..];
+    self.just(kind)
+  }
+
   fn whitespace(&mut self, c: char) {
     if c == '\n' {
       self.line += 1;
@@ -84,20 +90,41 @@ impl<'src> Iterator for Scanner<'src> {
         ')' => self.single(TokenKind::RightParen),
         '{' => self.single(TokenKind::LeftBrace),
         '}' => self.single(TokenKind::RightBrace),
+        '[' => self.single(TokenKind::LeftBracket),
+        ']' => self.single(TokenKind::RightBracket),
         ',' => self.single(TokenKind::Comma),
-        '.' => self.single(TokenKind::Dot),
-        '-' => self.single(TokenKind::Minus),
-        '+' => self.single(TokenKind::Plus),
         ';' => self.single(TokenKind::SemiColon),
-        '*' => self.single(TokenKind::Star),
-        '?' => self.single(TokenKind::Question),
         ':' => self.single(TokenKind::Colon),
+        '&' => self.single(TokenKind::Ampersand),
+        '|' => self.single(TokenKind::Pipe),
+        '^' => self.single(TokenKind::Caret),
+        '~' => self.single(TokenKind::Tilde),
 
         // multi character
         '!' => if_next_else('=', TokenKind::BangEqual, TokenKind::Bang),
+        '=' if c_rest.starts_with('>') => self.multi(2, TokenKind::EqualGreater),
         '=' => if_next_else('=', TokenKind::EqualEqual, TokenKind::Equal),
+        '<' if c_rest.starts_with('<') => self.multi(2, TokenKind::LessLess),
         '<' => if_next_else('=', TokenKind::LessEqual, TokenKind::Less),
+        '>' if c_rest.starts_with('>') => self.multi(2, TokenKind::GreaterGreater),
         '>' => if_next_else('=', TokenKind::GreaterEqual, TokenKind::Greater),
+        '?' if c_rest.starts_with('?') => self.multi(2, TokenKind::QuestionQuestion),
+        // `a?.5:1` is a ternary, as in JavaScript.
+        '?'
+          if c_rest.starts_with('.') && !c_rest[1..].starts_with(|c: char| c.is_ascii_digit()) =>
+        {
+          self.multi(2, TokenKind::QuestionDot)
+        }
+        '?' => self.single(TokenKind::Question),
+        '.' if c_rest.starts_with(".=") => self.multi(3, TokenKind::DotDotEqual),
+        '.' => if_next_else('.', TokenKind::DotDot, TokenKind::Dot),
+        '+' if c_rest.starts_with('+') => self.multi(2, TokenKind::PlusPlus),
+        '+' => if_next_else('=', TokenKind::PlusEqual, TokenKind::Plus),
+        '-' if c_rest.starts_with('-') => self.multi(2, TokenKind::MinusMinus),
+        '-' => if_next_else('=', TokenKind::MinusEqual, TokenKind::Minus),
+        '*' if c_rest.starts_with('*') => self.multi(2, TokenKind::StarStar),
+        '*' => if_next_else('=', TokenKind::StarEqual, TokenKind::Star),
+        '%' => if_next_else('=', TokenKind::PercentEqual, TokenKind::Percent),
 
         '"' => match self.string() {
           Ok(token) => token,
@@ -115,7 +142,11 @@ impl<'src> Iterator for Scanner<'src> {
               Err(e) => return Some(Err(e)),
             }
           }
-          self.single(TokenKind::Slash)
+          if c_rest.starts_with('=') {
+            self.multi(2, TokenKind::SlashEqual)
+          } else {
+            self.single(TokenKind::Slash)
+          }
         }
         // numbers
         '0'..='9' => self.number(),
@@ -154,7 +185,7 @@ mod tests {
   #[test]
   fn lex_single_tokens_ok() {
     // arrange
-    let input = "(){},.-+;:?";
+    let input = "(){}[],.-+;:?&|^~";
     let mut scanner = Scanner::new(input);
 
     // act and assert
@@ -169,6 +200,8 @@ mod tests {
     assert_just(TokenKind::RightParen);
     assert_just(TokenKind::LeftBrace);
     assert_just(TokenKind::RightBrace);
+    assert_just(TokenKind::LeftBracket);
+    assert_just(TokenKind::RightBracket);
     assert_just(TokenKind::Comma);
     assert_just(TokenKind::Dot);
     assert_just(TokenKind::Minus);
@@ -176,12 +209,16 @@ mod tests {
     assert_just(TokenKind::SemiColon);
     assert_just(TokenKind::Colon);
     assert_just(TokenKind::Question);
+    assert_just(TokenKind::Ampersand);
+    assert_just(TokenKind::Pipe);
+    assert_just(TokenKind::Caret);
+    assert_just(TokenKind::Tilde);
     assert_none!(scanner.next());
   }
 
   #[test]
   fn lex_multi_character_ok() {
-    let source = "< <= >= == <= !!=";
+    let source = "< <= >= == <= !!= . .. ..= ... => ==> + ++ +++ += - -- -= * *= / /= % %= ** *** << <<= >> >>= ?? ??? ?. ?..";
     let mut scanner = Scanner::new(source);
 
     let mut assert_just = |expected_kind| {
@@ -199,6 +236,53 @@ mod tests {
     assert_just(TokenKind::LessEqual);
     assert_just(TokenKind::Bang);
     assert_just(TokenKind::BangEqual);
+    assert_just(TokenKind::Dot);
+    assert_just(TokenKind::DotDot);
+    assert_just(TokenKind::DotDotEqual);
+    assert_just(TokenKind::DotDot);
+    assert_just(TokenKind::Dot);
+    assert_just(TokenKind::EqualGreater);
+    assert_just(TokenKind::EqualEqual);
+    assert_just(TokenKind::Greater);
+    assert_just(TokenKind::Plus);
+    assert_just(TokenKind::PlusPlus);
+    assert_just(TokenKind::PlusPlus);
+    assert_just(TokenKind::Plus);
+    assert_just(TokenKind::PlusEqual);
+    assert_just(TokenKind::Minus);
+    assert_just(TokenKind::MinusMinus);
+    assert_just(TokenKind::MinusEqual);
+    assert_just(TokenKind::Star);
+    assert_just(TokenKind::StarEqual);
+    assert_just(TokenKind::Slash);
+    assert_just(TokenKind::SlashEqual);
+    assert_just(TokenKind::Percent);
+    assert_just(TokenKind::PercentEqual);
+    assert_just(TokenKind::StarStar);
+    assert_just(TokenKind::StarStar);
+    assert_just(TokenKind::Star);
+    assert_just(TokenKind::LessLess);
+    assert_just(TokenKind::LessLess);
+    assert_just(TokenKind::Equal);
+    assert_just(TokenKind::GreaterGreater);
+    assert_just(TokenKind::GreaterGreater);
+    assert_just(TokenKind::Equal);
+    assert_just(TokenKind::QuestionQuestion);
+    assert_just(TokenKind::QuestionQuestion);
+    assert_just(TokenKind::Question);
+    assert_just(TokenKind::QuestionDot);
+    assert_just(TokenKind::QuestionDot);
+    assert_just(TokenKind::Dot);
+    assert_none!(scanner.next());
+  }
+
+  #[test]
+  fn lex_question_before_number_ok() {
+    let mut scanner = Scanner::new("?.5");
+
+    assert_matches!(scanner.next(), Some(Ok(Token { kind: TokenKind::Question, .. })));
+    assert_matches!(scanner.next(), Some(Ok(Token { kind: TokenKind::Dot, .. })));
+    assert_matches!(scanner.next(), Some(Ok(Token { kind: TokenKind::Number(5.0), .. })));
     assert_none!(scanner.next());
   }
 
@@ -225,8 +309,7 @@ mod tests {
     assert_just(TokenKind::Star, 3);
     assert_just(TokenKind::Plus, 3);
     assert_just(TokenKind::Minus, 3);
-    assert_just(TokenKind::Slash, 3);
-    assert_just(TokenKind::Equal, 3);
+    assert_just(TokenKind::SlashEqual, 3);
     assert_just(TokenKind::Less, 3);
     assert_just(TokenKind::Greater, 3);
     assert_just(TokenKind::LessEqual, 3);
@@ -236,13 +319,13 @@ mod tests {
 
   #[test]
   fn lex_invalid_character() {
-    let src = r#"identifier "string literal" % identifier2"#;
+    let src = r#"identifier "string literal" @ identifier2"#;
     let mut scanner = Scanner::new(src);
 
     let next = assert_some!(scanner.nth(2));
     let error = assert_err!(next);
 
-    assert_matches!(error, Error::InvalidCharacter { character: "%", line: 1 });
+    assert_matches!(error, Error::InvalidCharacter { character: "@", line: 1 });
     let next = assert_some!(scanner.next());
     let token = assert_ok!(next);
     assert_matches!(
@@ -25,29 +25,51 @@ impl Display for Token<'_> {
 #[derive(Debug, PartialEq)]
 pub enum TokenKind {
   // Single-character tokens
+  Ampersand,
+  Caret,
   Colon,
   Comma,
   Dot,
   LeftBrace,
+  LeftBracket,
   LeftParen,
   Minus,
+  Percent,
+  Pipe,
   Plus,
   Question,
   RightBrace,
+  RightBracket,
   RightParen,
   SemiColon,
   Slash,
   Star,
+  Tilde,
 
-  // One or two character tokens
+  // One or more character tokens
   Bang,
   BangEqual,
+  DotDot,
+  DotDotEqual,
   Equal,
   EqualEqual,
+  EqualGreater,
   Greater,
   GreaterEqual,
+  GreaterGreater,
   Less,
   LessEqual,
+  LessLess,
+  MinusEqual,
+  MinusMinus,
+  PercentEqual,
+  PlusEqual,
+  PlusPlus,
+  QuestionDot,
+  QuestionQuestion,
+  SlashEqual,
+  StarEqual,
+  StarStar,
 
   // Literals
   Identifier,
@@ -56,12 +78,15 @@ pub enum TokenKind {
 
   // Keywords
   And,
+  Break,
   Class,
+  Continue,
   Else,
   False,
   Fun,
   For,
   If,
+  In,
   Nil,
   Or,
   Print,
@@ -76,43 +101,68 @@ pub enum TokenKind {
 impl Display for TokenKind {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     match self {
+      TokenKind::Ampersand => write!(f, "&"),
       TokenKind::And => write!(f, "&&"),
       TokenKind::Bang => write!(f, "!"),
       TokenKind::BangEqual => write!(f, "!="),
+      TokenKind::Break => write!(f, "break"),
+      TokenKind::Caret => write!(f, "^"),
       TokenKind::Class => write!(f, "class"),
       TokenKind::Colon => write!(f, ":"),
       TokenKind::Comma => write!(f, ","),
+      TokenKind::Continue => write!(f, "continue"),
       TokenKind::Dot => write!(f, "."),
+      TokenKind::DotDot => write!(f, ".."),
+      TokenKind::DotDotEqual => write!(f, "..="),
       TokenKind::Else => write!(f, "else"),
       TokenKind::Equal => write!(f, "="),
       TokenKind::EqualEqual => write!(f, "=="),
+      TokenKind::EqualGreater => write!(f, "=>"),
       TokenKind::False => write!(f, "false"),
       TokenKind::For => write!(f, "for"),
       TokenKind::Fun => write!(f, "fun"),
       TokenKind::Greater => write!(f, ">"),
       TokenKind::GreaterEqual => write!(f, ">="),
+      TokenKind::GreaterGreater => write!(f, ">>"),
       TokenKind::Identifier => write!(f, "IDENT"),
       TokenKind::Number(n) => write!(f, "{}", n),
       TokenKind::If => write!(f, "if"),
+      TokenKind::In => write!(f, "in"),
       TokenKind::LeftBrace => write!(f, "{{"),
+      TokenKind::LeftBracket => write!(f, "["),
       TokenKind::LeftParen => write!(f, "("),
       TokenKind::Less => write!(f, "<"),
       TokenKind::LessEqual => write!(f, "<="),
+      TokenKind::LessLess => write!(f, "<<"),
       TokenKind::Minus => write!(f, "-"),
+      TokenKind::MinusEqual => write!(f, "-="),
+      TokenKind::MinusMinus => write!(f, "--"),
       TokenKind::Nil => write!(f, "nil"),
       TokenKind::Or => write!(f, "or"),
+      TokenKind::Percent => write!(f, "%"),
+      TokenKind::PercentEqual => write!(f, "%="),
+      TokenKind::Pipe => write!(f, "|"),
       TokenKind::Plus => write!(f, "+"),
+      TokenKind::PlusEqual => write!(f, "+="),
+      TokenKind::PlusPlus => write!(f, "++"),
       TokenKind::Print => write!(f, "print"),
       TokenKind::Question => write!(f, "?"),
+      TokenKind::QuestionDot => write!(f, "?."),
+      TokenKind::QuestionQuestion => write!(f, "??"),
       TokenKind::Return => write!(f, "return"),
       TokenKind::RightBrace => write!(f, "}}"),
+      TokenKind::RightBracket => write!(f, "]"),
       TokenKind::RightParen => write!(f, ")"),
       TokenKind::SemiColon => write!(f, ";"),
       TokenKind::Slash => write!(f, "/"),
+      TokenKind::SlashEqual => write!(f, "/="),
       TokenKind::Star => write!(f, "*"),
+      TokenKind::StarEqual => write!(f, "*="),
+      TokenKind::StarStar => write!(f, "**"),
       TokenKind::String(s) => write!(f, "{:?}", s),
       TokenKind::Super => write!(f, "super"),
       TokenKind::This => write!(f, "this"),
+      TokenKind::Tilde => write!(f, "~"),
       TokenKind::True => write!(f, "true"),
       TokenKind::Var => write!(f, "var"),
       TokenKind::While => write!(f, "while"),